@@ -0,0 +1,61 @@
+// Control de acceso: un owner (el admin) y moderadores con permisos puntuales
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{DataKey, Error};
+
+// Permisos que el owner puede delegar a un moderador
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Permiso {
+    ResetContador = 1,
+    SetLimite = 2,      // También el cooldown entre saludos
+    Moderar = 3,        // Ocultar saludos y banear usuarios
+}
+
+// El owner actual (o NoInicializado)
+pub fn owner(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NoInicializado)
+}
+
+// El caller firma y tiene que ser el owner
+pub fn require_owner(env: &Env, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if *caller != owner(env)? {
+        return Err(Error::NoAutorizado);
+    }
+    Ok(())
+}
+
+// El caller firma y tiene que ser el owner o un moderador con `permiso`
+pub fn require_permiso(env: &Env, caller: &Address, permiso: Permiso) -> Result<(), Error> {
+    caller.require_auth();
+
+    if *caller == owner(env)? || permisos(env, caller).contains(permiso) {
+        return Ok(());
+    }
+    Err(Error::NoAutorizado)
+}
+
+pub fn permisos(env: &Env, moderador: &Address) -> Vec<Permiso> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Moderador(moderador.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Sin permisos = deja de ser moderador
+pub fn set_permisos(env: &Env, moderador: &Address, permisos: &Vec<Permiso>) {
+    let key = DataKey::Moderador(moderador.clone());
+
+    if permisos.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, permisos);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+    }
+}
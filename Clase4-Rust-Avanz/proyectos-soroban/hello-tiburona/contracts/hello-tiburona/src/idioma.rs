@@ -0,0 +1,85 @@
+// Saludos multilingües: plantillas por código de idioma y el idioma preferido de cada usuario
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::{DataKey, Error};
+
+// Largo máximo de una plantilla (en bytes)
+pub const MAX_LARGO_PLANTILLA: u32 = 64;
+
+// Lugar de la plantilla donde va el nombre del usuario
+const MARCADOR: &[u8] = b"{}";
+
+// Se usa si no hay plantilla para el idioma del usuario ni para el idioma por defecto
+pub const PLANTILLA_BASE: &str = "Hola, {}!";
+const IDIOMA_BASE: Symbol = symbol_short!("es");
+
+// No vacía, hasta MAX_LARGO_PLANTILLA y con exactamente un `{}`
+pub fn validar_plantilla(plantilla: &String) -> Result<(), Error> {
+    if plantilla.is_empty() || plantilla.len() > MAX_LARGO_PLANTILLA {
+        return Err(Error::PlantillaInvalida);
+    }
+
+    let bytes = plantilla.to_bytes().to_alloc_vec();
+    let marcadores = bytes.windows(MARCADOR.len()).filter(|ventana| *ventana == MARCADOR).count();
+    if marcadores != 1 {
+        return Err(Error::PlantillaInvalida);
+    }
+    Ok(())
+}
+
+pub fn plantilla(env: &Env, idioma: &Symbol) -> Option<String> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Plantilla(idioma.clone()))
+}
+
+// Sin plantilla = el idioma deja de estar soportado
+pub fn set_plantilla(env: &Env, idioma: &Symbol, plantilla: Option<String>) {
+    let key = DataKey::Plantilla(idioma.clone());
+    match plantilla {
+        Some(plantilla) => env.storage().instance().set(&key, &plantilla),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+pub fn idioma_por_defecto(env: &Env) -> Symbol {
+    env.storage()
+        .instance()
+        .get(&DataKey::IdiomaPorDefecto)
+        .unwrap_or(IDIOMA_BASE)
+}
+
+pub fn idioma(env: &Env, usuario: &Address) -> Option<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Idioma(usuario.clone()))
+}
+
+pub fn set_idioma(env: &Env, usuario: &Address, idioma: &Symbol) {
+    let key = DataKey::Idioma(usuario.clone());
+    env.storage()
+        .persistent()
+        .set(&key, idioma);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 100, 100);
+}
+
+// Arma el saludo: idioma del usuario -> idioma por defecto -> PLANTILLA_BASE
+pub fn componer(env: &Env, usuario: &Address, nombre: &String) -> String {
+    let plantilla = idioma(env, usuario)
+        .and_then(|idioma| plantilla(env, &idioma))
+        .or_else(|| plantilla(env, &idioma_por_defecto(env)))
+        .unwrap_or(String::from_str(env, PLANTILLA_BASE));
+
+    let plantilla = plantilla.to_bytes().to_alloc_vec();
+    let nombre = nombre.to_bytes().to_alloc_vec();
+    let inicio = plantilla
+        .windows(MARCADOR.len())
+        .position(|ventana| ventana == MARCADOR)
+        .unwrap_or(plantilla.len());
+    let fin = (inicio + MARCADOR.len()).min(plantilla.len());
+
+    let saludo = [&plantilla[..inicio], &nombre[..], &plantilla[fin..]].concat();
+    String::from_bytes(env, &saludo)
+}
@@ -1,13 +1,22 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracterror, contracttype,
-    Env, Symbol, Address, String
+    contract, contractimpl, contracterror, contractevent, contracttype,
+    Env, Symbol, Address, String, Vec
 };  
 
 // Usar wee_alloc para optimizar el uso de memoria (Error en consola)
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+mod access;
+mod idioma;
+mod moderacion;
+mod recompensa;
+pub use access::Permiso;
+pub use idioma::MAX_LARGO_PLANTILLA;
+pub use moderacion::{MAX_LARGO_PALABRA, MAX_PALABRAS};
+pub use recompensa::{ConfigRecompensa, ModoRecompensa};
+
 // Definir errores 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -17,6 +26,17 @@ pub enum Error {
     NombreMuyLargo = 2,
     NoAutorizado = 3,
     NoInicializado = 4,
+    YaInicializado = 5,
+    SinTransferencia = 6,   // No hay transferencia de admin pendiente
+    PalabraProhibida = 7,
+    EnCooldown = 8,         // Saludó hace menos de `Cooldown` ledgers
+    UsuarioBaneado = 9,
+    SinSaludo = 10,         // No hay saludo para ocultar
+    DemasiadasPalabras = 11,
+    PalabraInvalida = 12,   // Vacía o más larga que MAX_LARGO_PALABRA
+    MontoInvalido = 13,     // Recompensa negativa
+    PlantillaInvalida = 14, // Vacía, más larga que MAX_LARGO_PLANTILLA o sin un único `{}`
+    IdiomaNoSoportado = 15, // No hay plantilla para ese idioma
 }
 
 // Definir data keys
@@ -28,6 +48,53 @@ pub enum DataKey {
     UltimoSaludo(Address),
     ContadorPorUsuario(Address),  // RETO 1
     LimiteCaracteres,              // RETO 3
+    Historial(Address, u32),       // Slot del ring buffer de saludos de un usuario
+    Feed(u32),                     // Slot del ring buffer del feed global
+    TotalFeed,                     // Saludos escritos en el feed (nunca se resetea)
+    AdminPendiente,                // Admin propuesto que todavía no aceptó
+    Moderador(Address),            // Permisos de un moderador
+    PalabrasProhibidas,            // Lista de palabras que no se aceptan en un saludo
+    Cooldown,                      // Ledgers mínimos entre saludos de un usuario
+    UltimoLedger(Address),         // Ledger del último saludo de un usuario
+    Baneado(Address),              // Usuarios que no pueden saludar
+    Recompensa,                    // Token, monto y modo de la recompensa de bienvenida
+    Recompensado(Address),         // Usuarios que ya cobraron la recompensa
+    Plantilla(Symbol),             // Plantilla de saludo por código de idioma
+    IdiomaPorDefecto,              // Idioma si el del usuario no tiene plantilla
+    Idioma(Address),               // Idioma preferido de un usuario
+}
+
+// Saludos que se guardan por usuario (los más viejos se sobrescriben)
+pub const MAX_HISTORIAL: u32 = 10;
+
+// Saludos que se guardan en el feed global
+pub const MAX_FEED: u32 = 20;
+
+// Un saludo del historial de un usuario
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Saludo {
+    pub nombre: String,
+    pub timestamp: u64,
+    pub feed: u32,      // Posición en el feed global (valor de TotalFeed al saludar)
+}
+
+// Un saludo del feed global
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SaludoFeed {
+    pub usuario: Address,
+    pub nombre: String,
+    pub timestamp: u64,
+}
+
+// Evento emitido en cada saludo para que el frontend lo escuche
+#[contractevent(topics = ["hello"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HelloEvent {
+    #[topic]
+    pub usuario: Address,
+    pub nombre: String,
 }
 
 // Definir el contrato
@@ -41,7 +108,7 @@ impl HelloContract {
     pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
         // 1) Verificar si ya está inicializado
         if env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NoInicializado);
+            return Err(Error::YaInicializado);
         }
 
         // 2) Guardar el administrador
@@ -53,6 +120,13 @@ impl HelloContract {
         // RETO 3: Inicializar límite de caracteres por defecto (32)
         env.storage().instance().set(&DataKey::LimiteCaracteres, &32u32);
 
+        // Saludo en español como plantilla inicial del idioma por defecto
+        idioma::set_plantilla(
+            &env,
+            &idioma::idioma_por_defecto(&env),
+            Some(String::from_str(&env, idioma::PLANTILLA_BASE))
+        );
+
         // 4) Extender TTL del contrato
         env.storage()
             .instance()
@@ -65,10 +139,10 @@ impl HelloContract {
         env: Env,
         usuario: Address,
         nombre: String
-    ) -> Result<Symbol, Error> {
+    ) -> Result<String, Error> {
         usuario.require_auth(); 
 
-        if nombre.len() == 0 { 
+        if nombre.is_empty() { 
             return Err(Error::NombreVacio); 
         } 
         
@@ -81,6 +155,10 @@ impl HelloContract {
         if nombre.len() > limite {
             return Err(Error::NombreMuyLargo);
         }
+
+        // Baneos, palabras prohibidas y cooldown
+        moderacion::check_saludo(&env, &usuario, &nombre)?;
+        moderacion::registrar_saludo(&env, &usuario);
        
         // Incrementar el contador de saludos -> Lee el contador, lo incrementa y lo guarda
         let key_contador = DataKey::ContadorSaludos;
@@ -110,6 +188,42 @@ impl HelloContract {
             .persistent()
             .extend_ttl(&key_contador_usuario, 100, 100);
 
+        // Historial: el contador del usuario indica el slot a sobrescribir
+        let timestamp = env.ledger().timestamp();
+        let total_feed: u32 = env.storage()
+            .instance()
+            .get(&DataKey::TotalFeed)
+            .unwrap_or(0);
+        let key_historial = DataKey::Historial(usuario.clone(), contador_usuario % MAX_HISTORIAL);
+        env.storage()
+            .persistent()
+            .set(&key_historial, &Saludo { nombre: nombre.clone(), timestamp, feed: total_feed });
+        env.storage()
+            .persistent()
+            .extend_ttl(&key_historial, 100, 100);
+
+        // Feed global: mismo esquema con su propio total
+        let key_feed = DataKey::Feed(total_feed % MAX_FEED);
+        let entrada = SaludoFeed {
+            usuario: usuario.clone(),
+            nombre: nombre.clone(),
+            timestamp,
+        };
+        env.storage()
+            .persistent()
+            .set(&key_feed, &entrada);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key_feed, 100, 100);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalFeed, &(total_feed + 1));
+
+        HelloEvent { usuario: usuario.clone(), nombre: nombre.clone() }.publish(&env);
+
+        // Recompensa de bienvenida (una sola vez por usuario)
+        recompensa::pagar(&env, &usuario);
+
         // Guardar el último saludo del usuario -> Guarda el nombre con la key del usuario
         env.storage()
             .persistent()
@@ -118,14 +232,14 @@ impl HelloContract {
         // Extender TTL del contrato -> persistent y instance en ese orden 
         env.storage()
             .persistent()
-            .extend_ttl(&DataKey::UltimoSaludo(usuario), 100, 100);
+            .extend_ttl(&DataKey::UltimoSaludo(usuario.clone()), 100, 100);
         
         env.storage()
             .instance()
             .extend_ttl(100, 100);
 
-        // Retornar el saludo 
-        Ok(Symbol::new(&env, "Hola"))
+        // Retornar el saludo en el idioma del usuario
+        Ok(idioma::componer(&env, &usuario, &nombre))
     }
 
     // 5) Funciones de consulta
@@ -143,6 +257,44 @@ impl HelloContract {
             .get(&DataKey::UltimoSaludo(usuario))
     }
 
+    // Historial de saludos de un usuario, del más nuevo al más viejo
+    // `start` = cuántos saludos saltear, `limit` = máximo a devolver
+    pub fn get_saludos(env: Env, usuario: Address, start: u32, limit: u32) -> Vec<Saludo> {
+        let total = Self::get_contador_usuario(env.clone(), usuario.clone());
+        let mut saludos = Vec::new(&env);
+
+        for i in ring_indices(total, MAX_HISTORIAL, start, limit) {
+            if let Some(saludo) = env.storage()
+                .persistent()
+                .get(&DataKey::Historial(usuario.clone(), i))
+            {
+                saludos.push_back(saludo);
+            }
+        }
+
+        saludos
+    }
+
+    // Últimos saludos de todos los usuarios, del más nuevo al más viejo
+    pub fn get_feed(env: Env, limit: u32) -> Vec<SaludoFeed> {
+        let total: u32 = env.storage()
+            .instance()
+            .get(&DataKey::TotalFeed)
+            .unwrap_or(0);
+        let mut feed = Vec::new(&env);
+
+        for i in ring_indices(total, MAX_FEED, 0, limit) {
+            if let Some(entrada) = env.storage()
+                .persistent()
+                .get(&DataKey::Feed(i))
+            {
+                feed.push_back(entrada);
+            }
+        }
+
+        feed
+    }
+
     // RETO 1: Función para obtener estadísticas por usuario
     pub fn get_contador_usuario(env: Env, usuario: Address) -> u32 {
         env.storage()
@@ -154,16 +306,8 @@ impl HelloContract {
     // 6) Funcion administrativa
 
     pub fn reset_contador(env: Env, caller: Address) -> Result<(), Error> {
-        // Obtener el administrador desde el almacenamiento 
-        let admin: Address = env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NoInicializado)?; // Recomendado el ? para propagar el error
-
-        // Solo el admin puede resetear el contador
-        if caller != admin {
-            return Err(Error::NoAutorizado);
-        }
+        // Owner o moderador con permiso, siempre con la firma del caller
+        access::require_permiso(&env, &caller, Permiso::ResetContador)?;
 
         // Reiniciar el contador de saludos
         env.storage()
@@ -174,57 +318,70 @@ impl HelloContract {
     }
 
     // RETO 2: Función para transferir ownership del contrato
+    // Paso 1: el owner propone al nuevo admin (no cambia nada todavía)
     pub fn transfer_admin(
         env: Env,
         caller: Address,
         nuevo_admin: Address
     ) -> Result<(), Error> {
-        // Verificar autenticación del caller
-        caller.require_auth();
+        access::require_owner(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminPendiente, &nuevo_admin);
+
+        // Extender TTL
+        env.storage()
+            .instance()
+            .extend_ttl(100, 100);
+
+        Ok(())
+    }
+
+    // Paso 2: el nuevo admin acepta con su firma
+    // Así no se puede transferir a una dirección equivocada o sin dueño
+    pub fn aceptar_admin(env: Env, nuevo_admin: Address) -> Result<(), Error> {
+        nuevo_admin.require_auth();
 
-        // Obtener el administrador actual
-        let admin_actual: Address = env.storage()
+        let pendiente: Address = env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NoInicializado)?;
+            .get(&DataKey::AdminPendiente)
+            .ok_or(Error::SinTransferencia)?;
 
-        // Verificar que el caller sea el admin actual
-        if caller != admin_actual {
+        if pendiente != nuevo_admin {
             return Err(Error::NoAutorizado);
         }
 
-        // Cambiar el admin al nuevo admin
         env.storage()
             .instance()
             .set(&DataKey::Admin, &nuevo_admin);
-
-        // Extender TTL
         env.storage()
             .instance()
-            .extend_ttl(100, 100);
+            .remove(&DataKey::AdminPendiente);
 
         Ok(())
     }
 
+    // El owner da permisos a un moderador (lista vacía = quitarlo)
+    pub fn set_moderador(
+        env: Env,
+        caller: Address,
+        moderador: Address,
+        permisos: Vec<Permiso>
+    ) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+        access::set_permisos(&env, &moderador, &permisos);
+        Ok(())
+    }
+
     // RETO 3: Función para configurar el límite de caracteres
     pub fn set_limite(
         env: Env,
         caller: Address,
         limite: u32
     ) -> Result<(), Error> {
-        // Verificar autenticación del caller
-        caller.require_auth();
-
-        // Obtener el administrador actual
-        let admin: Address = env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NoInicializado)?;
-
-        // Solo el admin puede cambiar el límite
-        if caller != admin {
-            return Err(Error::NoAutorizado);
-        }
+        // Owner o moderador con permiso, siempre con la firma del caller
+        access::require_permiso(&env, &caller, Permiso::SetLimite)?;
 
         // Guardar el nuevo límite
         env.storage()
@@ -239,6 +396,182 @@ impl HelloContract {
         Ok(())
     }
 
+    // Moderación: el owner define la lista completa de palabras prohibidas
+    pub fn set_palabras_prohibidas(
+        env: Env,
+        caller: Address,
+        palabras: Vec<String>
+    ) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+        moderacion::validar_palabras(&palabras)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PalabrasProhibidas, &palabras);
+
+        Ok(())
+    }
+
+    // Anti-spam: ledgers mínimos entre saludos (mismo permiso que el límite)
+    pub fn set_cooldown(env: Env, caller: Address, ledgers: u32) -> Result<(), Error> {
+        access::require_permiso(&env, &caller, Permiso::SetLimite)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Cooldown, &ledgers);
+
+        Ok(())
+    }
+
+    // Un moderador banea (o desbanea) a un usuario
+    pub fn set_baneado(
+        env: Env,
+        caller: Address,
+        usuario: Address,
+        baneado: bool
+    ) -> Result<(), Error> {
+        access::require_permiso(&env, &caller, Permiso::Moderar)?;
+        moderacion::set_baneado(&env, &usuario, baneado);
+        Ok(())
+    }
+
+    // Un moderador oculta el último saludo de un usuario
+    // (lo saca del último saludo, del historial y del feed)
+    pub fn ocultar_saludo(env: Env, caller: Address, usuario: Address) -> Result<(), Error> {
+        access::require_permiso(&env, &caller, Permiso::Moderar)?;
+
+        let key_ultimo = DataKey::UltimoSaludo(usuario.clone());
+        if !env.storage().persistent().has(&key_ultimo) {
+            return Err(Error::SinSaludo);
+        }
+        env.storage().persistent().remove(&key_ultimo);
+
+        // El slot más nuevo del historial es el del último saludo
+        let total = Self::get_contador_usuario(env.clone(), usuario.clone());
+        let key_historial = DataKey::Historial(usuario.clone(), (total - 1) % MAX_HISTORIAL);
+        let saludo: Option<Saludo> = env.storage().persistent().get(&key_historial);
+        env.storage().persistent().remove(&key_historial);
+
+        // Su slot del feed, salvo que ya lo hayan sobrescrito saludos más nuevos
+        if let Some(saludo) = saludo {
+            let total_feed: u32 = env.storage()
+                .instance()
+                .get(&DataKey::TotalFeed)
+                .unwrap_or(0);
+            if total_feed - saludo.feed <= MAX_FEED {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::Feed(saludo.feed % MAX_FEED));
+            }
+        }
+
+        Ok(())
+    }
+
+    // El owner configura la recompensa de bienvenida (monto 0 = desactivarla)
+    // Modo Pool: hay que depositar el token en este contrato
+    // Modo Mint: este contrato tiene que ser admin del token
+    pub fn set_recompensa(
+        env: Env,
+        caller: Address,
+        token: Address,
+        monto: i128,
+        modo: ModoRecompensa
+    ) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+        recompensa::set_config(&env, token, monto, modo)
+    }
+
+    pub fn get_recompensa(env: Env) -> Option<ConfigRecompensa> {
+        recompensa::config(&env)
+    }
+
+    pub fn fue_recompensado(env: Env, usuario: Address) -> bool {
+        recompensa::fue_recompensado(&env, &usuario)
+    }
+
+    // El usuario elige en qué idioma lo saludan (tiene que tener plantilla)
+    pub fn set_idioma(env: Env, usuario: Address, idioma: Symbol) -> Result<(), Error> {
+        usuario.require_auth();
+
+        if idioma::plantilla(&env, &idioma).is_none() {
+            return Err(Error::IdiomaNoSoportado);
+        }
+        idioma::set_idioma(&env, &usuario, &idioma);
+        Ok(())
+    }
+
+    // El owner registra (o reemplaza) la plantilla de un idioma, con `{}` donde va el nombre
+    pub fn set_plantilla(
+        env: Env,
+        caller: Address,
+        idioma: Symbol,
+        plantilla: String
+    ) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+        idioma::validar_plantilla(&plantilla)?;
+        idioma::set_plantilla(&env, &idioma, Some(plantilla));
+        Ok(())
+    }
+
+    // Los usuarios con ese idioma pasan a recibir el idioma por defecto
+    pub fn quitar_plantilla(env: Env, caller: Address, idioma: Symbol) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+        idioma::set_plantilla(&env, &idioma, None);
+        Ok(())
+    }
+
+    pub fn set_idioma_por_defecto(env: Env, caller: Address, idioma: Symbol) -> Result<(), Error> {
+        access::require_owner(&env, &caller)?;
+
+        if idioma::plantilla(&env, &idioma).is_none() {
+            return Err(Error::IdiomaNoSoportado);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::IdiomaPorDefecto, &idioma);
+
+        Ok(())
+    }
+
+    pub fn get_idioma(env: Env, usuario: Address) -> Option<Symbol> {
+        idioma::idioma(&env, &usuario)
+    }
+
+    pub fn get_plantilla(env: Env, idioma: Symbol) -> Option<String> {
+        idioma::plantilla(&env, &idioma)
+    }
+
+    pub fn get_idioma_por_defecto(env: Env) -> Symbol {
+        idioma::idioma_por_defecto(&env)
+    }
+
+    pub fn get_palabras_prohibidas(env: Env) -> Vec<String> {
+        moderacion::palabras_prohibidas(&env)
+    }
+
+    pub fn get_cooldown(env: Env) -> u32 {
+        moderacion::cooldown(&env)
+    }
+
+    pub fn is_baneado(env: Env, usuario: Address) -> bool {
+        moderacion::is_baneado(&env, &usuario)
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        access::owner(&env).ok()
+    }
+
+    pub fn get_admin_pendiente(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AdminPendiente)
+    }
+
+    pub fn get_permisos(env: Env, moderador: Address) -> Vec<Permiso> {
+        access::permisos(&env, &moderador)
+    }
+
     // RETO 3: Función para consultar el límite actual
     pub fn get_limite(env: Env) -> u32 {
         env.storage()
@@ -246,4 +579,15 @@ impl HelloContract {
             .get(&DataKey::LimiteCaracteres)
             .unwrap_or(32)
     }
-}
\ No newline at end of file
+}
+
+// Slots de un ring buffer de `capacidad` con `total` escrituras, del más
+// nuevo al más viejo, salteando `start` y devolviendo hasta `limit`
+fn ring_indices(total: u32, capacidad: u32, start: u32, limit: u32) -> impl Iterator<Item = u32> {
+    let guardados = total.min(capacidad);
+    let fin = start.saturating_add(limit).min(guardados);
+
+    (start.min(fin)..fin).map(move |i| (total - 1 - i) % capacidad)
+}
+
+mod test;
@@ -0,0 +1,89 @@
+// Moderación y anti-spam: palabras prohibidas, cooldown entre saludos y baneos
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::{DataKey, Error};
+
+// Máximo de palabras prohibidas y largo máximo de cada una
+pub const MAX_PALABRAS: u32 = 50;
+pub const MAX_LARGO_PALABRA: u32 = 32;
+
+// Valida la lista antes de guardarla
+pub fn validar_palabras(palabras: &Vec<String>) -> Result<(), Error> {
+    if palabras.len() > MAX_PALABRAS {
+        return Err(Error::DemasiadasPalabras);
+    }
+    for palabra in palabras.iter() {
+        if palabra.is_empty() || palabra.len() > MAX_LARGO_PALABRA {
+            return Err(Error::PalabraInvalida);
+        }
+    }
+    Ok(())
+}
+
+pub fn palabras_prohibidas(env: &Env) -> Vec<String> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PalabrasProhibidas)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn cooldown(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Cooldown)
+        .unwrap_or(0)
+}
+
+pub fn is_baneado(env: &Env, usuario: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Baneado(usuario.clone()))
+}
+
+// Chequeos de `hello` antes de guardar nada
+pub fn check_saludo(env: &Env, usuario: &Address, nombre: &String) -> Result<(), Error> {
+    if is_baneado(env, usuario) {
+        return Err(Error::UsuarioBaneado);
+    }
+
+    // Comparación sin distinguir mayúsculas (ASCII)
+    let texto = nombre.to_bytes().to_alloc_vec();
+    for palabra in palabras_prohibidas(env).iter() {
+        let palabra = palabra.to_bytes().to_alloc_vec();
+        if texto.windows(palabra.len()).any(|ventana| ventana.eq_ignore_ascii_case(&palabra)) {
+            return Err(Error::PalabraProhibida);
+        }
+    }
+
+    let ultimo: Option<u32> = env.storage()
+        .persistent()
+        .get(&DataKey::UltimoLedger(usuario.clone()));
+    if let Some(ultimo) = ultimo {
+        if env.ledger().sequence() < ultimo.saturating_add(cooldown(env)) {
+            return Err(Error::EnCooldown);
+        }
+    }
+
+    Ok(())
+}
+
+// Guarda el ledger del saludo para el cooldown
+pub fn registrar_saludo(env: &Env, usuario: &Address) {
+    let key = DataKey::UltimoLedger(usuario.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().sequence());
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, 100, 100);
+}
+
+pub fn set_baneado(env: &Env, usuario: &Address, baneado: bool) {
+    let key = DataKey::Baneado(usuario.clone());
+    if baneado {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
@@ -0,0 +1,82 @@
+// Recompensa de bienvenida: un pago único de un token SEP-41 (CODEBIT) por usuario
+use soroban_sdk::{contractclient, contracttype, token, Address, Env};
+
+use crate::{DataKey, Error};
+
+// Solo necesitamos `mint` del token (TokenCodebit lo expone con esta firma)
+#[allow(dead_code)]
+#[contractclient(name = "MintClient")]
+pub trait Mint {
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
+// De dónde sale la recompensa
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ModoRecompensa {
+    Pool,       // Se transfiere desde tokens depositados en este contrato
+    Mint,       // Este contrato es minter (admin) del token y mintea cada pago
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigRecompensa {
+    pub token: Address,
+    pub monto: i128,
+    pub modo: ModoRecompensa,
+}
+
+pub fn config(env: &Env) -> Option<ConfigRecompensa> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Recompensa)
+}
+
+// Monto 0 = desactivar la recompensa
+pub fn set_config(env: &Env, token: Address, monto: i128, modo: ModoRecompensa) -> Result<(), Error> {
+    if monto < 0 {
+        return Err(Error::MontoInvalido);
+    }
+
+    if monto == 0 {
+        env.storage().instance().remove(&DataKey::Recompensa);
+    } else {
+        env.storage()
+            .instance()
+            .set(&DataKey::Recompensa, &ConfigRecompensa { token, monto, modo });
+    }
+    Ok(())
+}
+
+pub fn fue_recompensado(env: &Env, usuario: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Recompensado(usuario.clone()))
+}
+
+// Paga la recompensa si está configurada y el usuario todavía no la cobró.
+// Si el pago falla (pool vacío, sin rol de minter) el saludo sigue igual
+// y la recompensa queda pendiente para el próximo saludo.
+pub fn pagar(env: &Env, usuario: &Address) {
+    let Some(config) = config(env) else {
+        return;
+    };
+    if fue_recompensado(env, usuario) {
+        return;
+    }
+
+    let pagado = match config.modo {
+        ModoRecompensa::Pool => token::TokenClient::new(env, &config.token)
+            .try_transfer(&env.current_contract_address(), usuario, &config.monto)
+            .is_ok_and(|r| r.is_ok()),
+        ModoRecompensa::Mint => MintClient::new(env, &config.token)
+            .try_mint(usuario, &config.monto)
+            .is_ok_and(|r| r.is_ok()),
+    };
+
+    if pagado {
+        let key = DataKey::Recompensado(usuario.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+    }
+}
@@ -1,8 +1,12 @@
-#[cfg(test)]
+#![cfg(test)]
 
     use super::*;
     // Al hacerlo en otro archivo importo dependencias 
-    use soroban_sdk::{Env, String, Symbol, testutils::Address};
+    use soroban_sdk::{
+        Env, Event as _, IntoVal, String, Symbol, Val, Vec, vec,
+        testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    };
+    use token_codebit::{TokenCodebit, TokenCodebitClient};
 
     #[test]
     fn test_initialize() {
@@ -22,7 +26,7 @@
 
 
     #[test]
-    #[should_panic(expected = "NoInicializado")]
+    #[should_panic(expected = "Error(Contract, #5)")] // YaInicializado
     fn test_no_reinicializar() {
         let env = Env::default();
         let contract_id = env.register(HelloContract, ());
@@ -39,6 +43,7 @@
     fn test_hello_exitoso() {
 
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register(HelloContract, ()); 
         let client = HelloContractClient::new(&env, &contract_id);
         
@@ -50,16 +55,17 @@
         let nombre = String::from_str(&env, "Ana");  // Con String -> String:: from_str() en lugar de Symbol::new()
         let resultado = client.hello(&usuario, &nombre); 
         
-        assert_eq!(resultado, Symbol::new(&env, "Hola"));
+        assert_eq!(resultado, String::from_str(&env, "Hola, Ana!"));
         assert_eq!(client.get_contador(), 1);
         assert_eq!(client.get_ultimo_saludo(&usuario), Some(nombre));
     }
 
 
     #[test]
-    #[should_panic(expected = "NombreVacio")]
+    #[should_panic(expected = "Error(Contract, #1)")] // NombreVacio
     fn test_nombre_vacio() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register(HelloContract, ());
         let client = HelloContractClient::new(&env, &contract_id);
         
@@ -81,23 +87,31 @@
         let client = HelloContractClient::new(&env, &contract_id);
         
         let admin = Address::generate(&env);
-        let _otro = Address::generate(&env);
+        let otro = Address::generate(&env);
         let usuario = Address::generate(&env);
         
         client.initialize(&admin);
         
         // Hacer saludos
-        client.hello(&usuario, &String::from_str(&env, "Test"));
+        let nombre = String::from_str(&env, "Test");
+        firmar(&env, &contract_id, &usuario, "hello", (&usuario, &nombre));
+        client.hello(&usuario, &nombre);
         assert_eq!(client.get_contador(), 1);
         
+        // Otro no puede hacerse pasar por el admin: firma él, no el admin
+        firmar(&env, &contract_id, &otro, "reset_contador", (&admin,));
+        assert!(client.try_reset_contador(&admin).is_err());
+        assert_eq!(client.get_contador(), 1);
+
         // Admin puede resetear
+        firmar(&env, &contract_id, &admin, "reset_contador", (&admin,));
         client.reset_contador(&admin);
         assert_eq!(client.get_contador(), 0);
     }
 
 
     #[test]
-    #[should_panic(expected = "NoAutorizado")]
+    #[should_panic(expected = "Error(Contract, #3)")] // NoAutorizado
     fn test_reset_no_autorizado() {
         let env = Env::default();
         let contract_id = env.register(HelloContract, ());
@@ -108,7 +122,533 @@
         
         client.initialize(&admin);
         
-        // Otro usuario intenta resetear
+        // Otro usuario intenta resetear con su propia firma
+        firmar(&env, &contract_id, &otro, "reset_contador", (&otro,));
         client.reset_contador(&otro); // Debe fallar 
     }
 
+
+    #[test]
+    fn test_moderador_con_permisos() {
+        let env = Env::default();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        client.initialize(&admin);
+
+        // Solo el owner nombra moderadores
+        let permisos = vec![&env, Permiso::ResetContador];
+        firmar(&env, &contract_id, &moderador, "set_moderador", (&moderador, &moderador, permisos.clone()));
+        assert_eq!(
+            client.try_set_moderador(&moderador, &moderador, &permisos),
+            Err(Ok(Error::NoAutorizado))
+        );
+
+        firmar(&env, &contract_id, &admin, "set_moderador", (&admin, &moderador, permisos.clone()));
+        client.set_moderador(&admin, &moderador, &permisos);
+        assert_eq!(client.get_permisos(&moderador), permisos);
+
+        // Puede resetear pero no cambiar el límite
+        firmar(&env, &contract_id, &moderador, "reset_contador", (&moderador,));
+        client.reset_contador(&moderador);
+
+        firmar(&env, &contract_id, &moderador, "set_limite", (&moderador, 10u32));
+        assert_eq!(client.try_set_limite(&moderador, &10), Err(Ok(Error::NoAutorizado)));
+
+        // Sin permisos deja de ser moderador
+        firmar(&env, &contract_id, &admin, "set_moderador", (&admin, &moderador, Vec::<Permiso>::new(&env)));
+        client.set_moderador(&admin, &moderador, &Vec::new(&env));
+
+        firmar(&env, &contract_id, &moderador, "reset_contador", (&moderador,));
+        assert_eq!(client.try_reset_contador(&moderador), Err(Ok(Error::NoAutorizado)));
+    }
+
+
+    #[test]
+    fn test_transfer_admin_dos_pasos() {
+        let env = Env::default();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let nuevo = Address::generate(&env);
+        let otro = Address::generate(&env);
+        client.initialize(&admin);
+
+        // Sin propuesta no hay nada que aceptar
+        firmar(&env, &contract_id, &nuevo, "aceptar_admin", (&nuevo,));
+        assert_eq!(client.try_aceptar_admin(&nuevo), Err(Ok(Error::SinTransferencia)));
+
+        // Solo el owner propone
+        firmar(&env, &contract_id, &otro, "transfer_admin", (&otro, &otro));
+        assert_eq!(client.try_transfer_admin(&otro, &otro), Err(Ok(Error::NoAutorizado)));
+
+        firmar(&env, &contract_id, &admin, "transfer_admin", (&admin, &nuevo));
+        client.transfer_admin(&admin, &nuevo);
+        assert_eq!(client.get_admin(), Some(admin.clone()));
+        assert_eq!(client.get_admin_pendiente(), Some(nuevo.clone()));
+
+        // Solo el propuesto acepta, y con su firma
+        firmar(&env, &contract_id, &otro, "aceptar_admin", (&otro,));
+        assert_eq!(client.try_aceptar_admin(&otro), Err(Ok(Error::NoAutorizado)));
+        firmar(&env, &contract_id, &otro, "aceptar_admin", (&nuevo,));
+        assert!(client.try_aceptar_admin(&nuevo).is_err());
+
+        firmar(&env, &contract_id, &nuevo, "aceptar_admin", (&nuevo,));
+        client.aceptar_admin(&nuevo);
+        assert_eq!(client.get_admin(), Some(nuevo.clone()));
+        assert_eq!(client.get_admin_pendiente(), None);
+
+        // El admin anterior ya no tiene permisos
+        firmar(&env, &contract_id, &admin, "set_limite", (&admin, 10u32));
+        assert_eq!(client.try_set_limite(&admin, &10), Err(Ok(Error::NoAutorizado)));
+
+        firmar(&env, &contract_id, &nuevo, "set_limite", (&nuevo, 10u32));
+        client.set_limite(&nuevo, &10);
+        assert_eq!(client.get_limite(), 10);
+    }
+
+
+    #[test]
+    fn test_historial_por_usuario() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+
+        // Más saludos que lugares en el historial: los más viejos se pisan
+        for i in 0..MAX_HISTORIAL + 2 {
+            env.ledger().set_timestamp(1_000 + i as u64);
+            client.hello(&usuario, &String::from_str(&env, if i % 2 == 0 { "Ana" } else { "Eva" }));
+        }
+
+        let saludos = client.get_saludos(&usuario, &0, &100);
+        assert_eq!(saludos.len(), MAX_HISTORIAL);
+        assert_eq!(saludos.get(0).unwrap().timestamp, 1_000 + MAX_HISTORIAL as u64 + 1);
+        assert_eq!(saludos.get(0).unwrap().nombre, String::from_str(&env, "Eva"));
+        assert_eq!(saludos.last().unwrap().timestamp, 1_002);
+
+        // Paginación
+        let pagina = client.get_saludos(&usuario, &8, &5);
+        assert_eq!(pagina.len(), 2);
+        assert_eq!(pagina.get(0).unwrap().timestamp, 1_003);
+        assert_eq!(client.get_saludos(&usuario, &MAX_HISTORIAL, &5).len(), 0);
+
+        // Otro usuario no tiene historial
+        assert_eq!(client.get_saludos(&Address::generate(&env), &0, &5).len(), 0);
+    }
+
+
+    #[test]
+    fn test_feed_global() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let ana = Address::generate(&env);
+        let eva = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.hello(&ana, &String::from_str(&env, "Ana"));
+        client.hello(&eva, &String::from_str(&env, "Eva"));
+
+        let feed = client.get_feed(&10);
+        assert_eq!(feed.len(), 2);
+        assert_eq!(feed.get(0).unwrap().usuario, eva);
+        assert_eq!(feed.get(1).unwrap().usuario, ana);
+        assert_eq!(client.get_feed(&1).len(), 1);
+
+        // El feed guarda solo los últimos MAX_FEED saludos
+        for _ in 0..MAX_FEED {
+            client.hello(&eva, &String::from_str(&env, "Eva"));
+        }
+        let feed = client.get_feed(&100);
+        assert_eq!(feed.len(), MAX_FEED);
+        assert!(feed.iter().all(|entrada| entrada.usuario == eva));
+    }
+
+
+    #[test]
+    fn test_evento_hello() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&usuario, &nombre);
+
+        // Hay que mirar los eventos antes de otra llamada al contrato
+        let evento = HelloEvent { usuario: usuario.clone(), nombre };
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "hello"), usuario).into_val(&env),
+                    evento.data(&env),
+                ),
+            ]
+        );
+    }
+
+
+
+    #[test]
+    fn test_palabras_prohibidas() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+
+        let palabras = vec![&env, String::from_str(&env, "spam")];
+
+        // Solo el owner maneja la lista
+        assert_eq!(
+            client.try_set_palabras_prohibidas(&usuario, &palabras),
+            Err(Ok(Error::NoAutorizado))
+        );
+        client.set_palabras_prohibidas(&admin, &palabras);
+        assert_eq!(client.get_palabras_prohibidas(), palabras);
+
+        // Sin importar mayúsculas ni dónde aparezca
+        assert_eq!(
+            client.try_hello(&usuario, &String::from_str(&env, "Ana SPAMmer")),
+            Err(Ok(Error::PalabraProhibida))
+        );
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+
+        // Validación de la lista
+        let vacia = vec![&env, String::from_str(&env, "")];
+        assert_eq!(
+            client.try_set_palabras_prohibidas(&admin, &vacia),
+            Err(Ok(Error::PalabraInvalida))
+        );
+        let mut muchas = Vec::new(&env);
+        for _ in 0..=MAX_PALABRAS {
+            muchas.push_back(String::from_str(&env, "spam"));
+        }
+        assert_eq!(
+            client.try_set_palabras_prohibidas(&admin, &muchas),
+            Err(Ok(Error::DemasiadasPalabras))
+        );
+    }
+
+
+    #[test]
+    fn test_cooldown_entre_saludos() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        let otro = Address::generate(&env);
+        client.initialize(&admin);
+        client.set_cooldown(&admin, &10);
+        assert_eq!(client.get_cooldown(), 10);
+
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&usuario, &nombre);
+        assert_eq!(client.try_hello(&usuario, &nombre), Err(Ok(Error::EnCooldown)));
+
+        // El cooldown es por usuario
+        client.hello(&otro, &nombre);
+
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        client.hello(&usuario, &nombre);
+        assert_eq!(client.get_contador_usuario(&usuario), 2);
+    }
+
+
+    #[test]
+    fn test_ocultar_saludo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+
+        client.hello(&usuario, &String::from_str(&env, "Ana"));
+        client.hello(&usuario, &String::from_str(&env, "Feo"));
+
+        // Sin permiso Moderar no puede
+        assert_eq!(
+            client.try_ocultar_saludo(&moderador, &usuario),
+            Err(Ok(Error::NoAutorizado))
+        );
+
+        client.set_moderador(&admin, &moderador, &vec![&env, Permiso::Moderar]);
+        client.ocultar_saludo(&moderador, &usuario);
+
+        // Desaparece de todos lados, el saludo anterior queda
+        assert_eq!(client.get_ultimo_saludo(&usuario), None);
+        let saludos = client.get_saludos(&usuario, &0, &10);
+        assert_eq!(saludos.len(), 1);
+        assert_eq!(saludos.get(0).unwrap().nombre, String::from_str(&env, "Ana"));
+        let feed = client.get_feed(&10);
+        assert_eq!(feed.len(), 1);
+        assert_eq!(feed.get(0).unwrap().nombre, String::from_str(&env, "Ana"));
+
+        assert_eq!(
+            client.try_ocultar_saludo(&moderador, &usuario),
+            Err(Ok(Error::SinSaludo))
+        );
+    }
+
+
+    #[test]
+    fn test_ocultar_saludo_repetido_en_el_feed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        let otro = Address::generate(&env);
+        client.initialize(&admin);
+
+        // Sin cooldown: el mismo saludo dos veces en el mismo ledger
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&usuario, &nombre);
+        client.hello(&otro, &String::from_str(&env, "Beto"));
+        client.hello(&usuario, &nombre);
+
+        // Se oculta la copia más nueva, no la primera que coincide
+        client.ocultar_saludo(&admin, &usuario);
+        let feed = client.get_feed(&10);
+        assert_eq!(feed.len(), 2);
+        assert_eq!(feed.get(0).unwrap().usuario, otro);
+        assert_eq!(feed.get(1).unwrap().usuario, usuario);
+        assert_eq!(client.get_saludos(&usuario, &0, &10).get(0).unwrap().feed, 0);
+    }
+
+
+    #[test]
+    fn test_baneo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+
+        assert_eq!(
+            client.try_set_baneado(&usuario, &usuario, &false),
+            Err(Ok(Error::NoAutorizado))
+        );
+
+        client.set_baneado(&admin, &usuario, &true);
+        assert!(client.is_baneado(&usuario));
+        let nombre = String::from_str(&env, "Ana");
+        assert_eq!(client.try_hello(&usuario, &nombre), Err(Ok(Error::UsuarioBaneado)));
+
+        client.set_baneado(&admin, &usuario, &false);
+        assert!(!client.is_baneado(&usuario));
+        client.hello(&usuario, &nombre);
+    }
+
+    #[test]
+    fn test_recompensa_desde_pool() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token = crear_codebit(&env, &admin);
+        client.initialize(&admin);
+
+        assert_eq!(
+            client.try_set_recompensa(&admin, &token.address, &-1, &ModoRecompensa::Pool),
+            Err(Ok(Error::MontoInvalido))
+        );
+        client.set_recompensa(&admin, &token.address, &10, &ModoRecompensa::Pool);
+
+        // Pool vacío: el saludo funciona y la recompensa queda pendiente
+        let ana = Address::generate(&env);
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&ana, &nombre);
+        assert_eq!(token.balance(&ana), 0);
+        assert!(!client.fue_recompensado(&ana));
+
+        // Con el pool cargado se paga una sola vez por usuario
+        token.mint(&contract_id, &15);
+        client.hello(&ana, &nombre);
+        client.hello(&ana, &nombre);
+        assert_eq!(token.balance(&ana), 10);
+        assert!(client.fue_recompensado(&ana));
+
+        // Quedan 5 en el pool, no alcanza para el próximo
+        let beto = Address::generate(&env);
+        client.hello(&beto, &String::from_str(&env, "Beto"));
+        assert_eq!(token.balance(&beto), 0);
+        assert_eq!(token.balance(&contract_id), 5);
+    }
+
+
+    #[test]
+    fn test_recompensa_minteando() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token = crear_codebit(&env, &admin);
+        client.initialize(&admin);
+        client.set_recompensa(&admin, &token.address, &10, &ModoRecompensa::Mint);
+        assert_eq!(
+            client.get_recompensa(),
+            Some(ConfigRecompensa {
+                token: token.address.clone(),
+                monto: 10,
+                modo: ModoRecompensa::Mint,
+            })
+        );
+
+        // El contrato pasa a ser admin (minter) del token
+        token.set_admin(&contract_id);
+
+        let ana = Address::generate(&env);
+        let nombre = String::from_str(&env, "Ana");
+        client.hello(&ana, &nombre);
+        client.hello(&ana, &nombre);
+        assert_eq!(token.balance(&ana), 10);
+        assert_eq!(token.total_supply(), 10);
+
+        // Monto 0 desactiva la recompensa
+        client.set_recompensa(&admin, &token.address, &0, &ModoRecompensa::Mint);
+        assert_eq!(client.get_recompensa(), None);
+        let beto = Address::generate(&env);
+        client.hello(&beto, &String::from_str(&env, "Beto"));
+        assert_eq!(token.balance(&beto), 0);
+    }
+
+    #[test]
+    fn test_saludo_multilingue() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let ana = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.initialize(&admin);
+        let es = Symbol::new(&env, "es");
+        let en = Symbol::new(&env, "en");
+        assert_eq!(client.get_idioma_por_defecto(), es);
+
+        // Sin plantilla no se puede elegir el idioma
+        assert_eq!(client.try_set_idioma(&bob, &en), Err(Ok(Error::IdiomaNoSoportado)));
+
+        client.set_plantilla(&admin, &en, &String::from_str(&env, "Hello, {}! Welcome"));
+        client.set_idioma(&bob, &en);
+        assert_eq!(client.get_idioma(&bob), Some(en.clone()));
+
+        let saludo = client.hello(&bob, &String::from_str(&env, "Bob"));
+        assert_eq!(saludo, String::from_str(&env, "Hello, Bob! Welcome"));
+        let saludo = client.hello(&ana, &String::from_str(&env, "Ana"));
+        assert_eq!(saludo, String::from_str(&env, "Hola, Ana!"));
+
+        // Cambiar el idioma por defecto afecta a quien no eligió
+        client.set_idioma_por_defecto(&admin, &en);
+        let saludo = client.hello(&ana, &String::from_str(&env, "Ana"));
+        assert_eq!(saludo, String::from_str(&env, "Hello, Ana! Welcome"));
+
+        // Si se quita la plantilla del usuario cae al idioma por defecto
+        client.set_idioma_por_defecto(&admin, &es);
+        client.quitar_plantilla(&admin, &en);
+        let saludo = client.hello(&bob, &String::from_str(&env, "Bob"));
+        assert_eq!(saludo, String::from_str(&env, "Hola, Bob!"));
+    }
+
+
+    #[test]
+    fn test_plantilla_invalida() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let usuario = Address::generate(&env);
+        client.initialize(&admin);
+        let pt = Symbol::new(&env, "pt");
+
+        assert_eq!(
+            client.try_set_plantilla(&usuario, &pt, &String::from_str(&env, "Olá, {}!")),
+            Err(Ok(Error::NoAutorizado))
+        );
+
+        let invalidas = [
+            "",
+            "Olá!",                  // Sin lugar para el nombre
+            "Olá, {} e {}!",         // Dos lugares
+            "Olá, {}! Bem-vindo à comunidade de builders da Stellar no Brasil!!",
+        ];
+        for plantilla in invalidas {
+            assert_eq!(
+                client.try_set_plantilla(&admin, &pt, &String::from_str(&env, plantilla)),
+                Err(Ok(Error::PlantillaInvalida))
+            );
+        }
+
+        let valida = "Olá, {}! Bem-vindo à comunidade de builders da Stellar!";
+        assert!(valida.len() as u32 <= MAX_LARGO_PLANTILLA);
+        client.set_plantilla(&admin, &pt, &String::from_str(&env, valida));
+        assert_eq!(client.get_plantilla(&pt), Some(String::from_str(&env, valida)));
+    }
+
+    // Despliega TokenCodebit con `admin` como admin del token
+    fn crear_codebit<'a>(env: &Env, admin: &Address) -> TokenCodebitClient<'a> {
+        let token_id = env.register(TokenCodebit, ());
+        let token = TokenCodebitClient::new(env, &token_id);
+        token.initialize(
+            admin,
+            &String::from_str(env, "Codebit"),
+            &String::from_str(env, "CODE"),
+            &0
+        );
+        token
+    }
+
+    // Firma solo `caller` para la próxima llamada a `fn_name` (en vez de mock_all_auths)
+    fn firmar<T>(env: &Env, contract_id: &Address, caller: &Address, fn_name: &str, args: T)
+    where
+        T: IntoVal<Env, Vec<Val>>,
+    {
+        env.mock_auths(&[MockAuth {
+            address: caller,
+            invoke: &MockAuthInvoke {
+                contract: contract_id,
+                fn_name,
+                args: args.into_val(env),
+                sub_invokes: &[],
+            },
+        }]);
+    }
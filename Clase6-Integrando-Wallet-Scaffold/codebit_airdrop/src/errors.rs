@@ -0,0 +1,36 @@
+// src/errors.rs
+use soroban_sdk::contracterror;
+
+/// Errores del airdrop de CODEBIT
+/// Los códigos empiezan en 1 (0 está reservado para "sin error")
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AirdropError {
+    /// El airdrop ya fue inicializado
+    AlreadyInitialized = 1,
+
+    /// Falta llamar initialize()
+    NotInitialized = 2,
+
+    /// La prueba Merkle no lleva a la raíz guardada
+    InvalidProof = 3,
+
+    /// La hoja ya fue reclamada
+    AlreadyClaimed = 4,
+
+    /// Pasó el deadline, ya no se puede reclamar
+    ClaimWindowClosed = 5,
+
+    /// Todavía no pasó el deadline, no se puede barrer el pool
+    ClaimWindowOpen = 6,
+
+    /// Monto del reclamo debe ser > 0
+    InvalidAmount = 7,
+
+    /// No quedan fondos sin reclamar en el pool
+    NothingToSweep = 8,
+
+    /// El deadline tiene que ser posterior al momento de inicializar
+    InvalidDeadline = 9,
+}
@@ -0,0 +1,213 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec
+};
+
+mod storage;
+mod errors;
+
+pub use storage::{AirdropConfig, Payout};
+use storage::DataKey;
+use errors::AirdropError;
+
+/// Hojas por palabra del bitmap de reclamos
+const BITS_PER_WORD: u32 = 128;
+
+/// Prefijos de dominio: una hoja nunca puede pasar por un nodo interno
+/// (ni al revés) aunque sus preimágenes tengan el mismo largo
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Interfaz mínima de TokenCodebit para el modo Mint
+/// (mint no es parte de SEP-41, por eso no está en token::TokenClient)
+#[contractclient(name = "CodebitMintClient")]
+pub trait CodebitMint {
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
+/// Trait del airdrop retroactivo de CODEBIT
+///
+/// Cada hoja del árbol es sha256(0x00 || index || address || amount) y
+/// cada nodo sha256(0x01 || izquierdo || derecho), ordenados según el bit
+/// de `index` en cada nivel (0 => nodo a la izquierda, 1 => a la derecha).
+pub trait AirdropTrait {
+    /// Configura token, raíz Merkle, deadline y modo de pago (una sola vez)
+    fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        merkle_root: BytesN<32>,
+        deadline: u64,
+        payout: Payout
+    ) -> Result<(), AirdropError>;
+
+    /// Reclama `amount` CODEBIT con la prueba de la hoja `index`
+    fn claim(
+        env: Env,
+        account: Address,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>
+    ) -> Result<(), AirdropError>;
+
+    /// El admin recupera los CODEBIT no reclamados después del deadline
+    fn sweep(env: Env, to: Address) -> Result<i128, AirdropError>;
+
+    // Métodos de consulta
+    fn is_claimed(env: Env, index: u32) -> bool;
+    fn config(env: Env) -> Result<AirdropConfig, AirdropError>;
+}
+
+#[contract]
+pub struct CodebitAirdrop;
+
+#[contractimpl]
+impl AirdropTrait for CodebitAirdrop {
+    fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        merkle_root: BytesN<32>,
+        deadline: u64,
+        payout: Payout
+    ) -> Result<(), AirdropError> {
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(AirdropError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        if deadline <= env.ledger().timestamp() {
+            return Err(AirdropError::InvalidDeadline);
+        }
+
+        let config = AirdropConfig { admin, token, merkle_root, deadline, payout };
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage().instance().extend_ttl(100_000, 200_000);
+
+        Ok(())
+    }
+
+    fn claim(
+        env: Env,
+        account: Address,
+        index: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>
+    ) -> Result<(), AirdropError> {
+        let config = read_config(&env)?;
+
+        // 1. Validaciones
+        if env.ledger().timestamp() > config.deadline {
+            return Err(AirdropError::ClaimWindowClosed);
+        }
+
+        if amount <= 0 {
+            return Err(AirdropError::InvalidAmount);
+        }
+
+        if is_claimed(&env, index) {
+            return Err(AirdropError::AlreadyClaimed);
+        }
+
+        // 2. Verificar la prueba contra la raíz
+        let mut node = leaf_hash(&env, index, &account, amount);
+        let mut position = index;
+        for sibling in proof.iter() {
+            node = hash_pair(&env, &node, &sibling, position & 1 == 1);
+            position >>= 1;
+        }
+        if node != config.merkle_root {
+            return Err(AirdropError::InvalidProof);
+        }
+
+        // 3. Marcar la hoja antes de pagar
+        set_claimed(&env, index);
+
+        // 4. Pagar (mint o desde el pool)
+        match config.payout {
+            Payout::Mint => {
+                CodebitMintClient::new(&env, &config.token).mint(&account, &amount);
+            }
+            Payout::Pool => {
+                token::TokenClient::new(&env, &config.token)
+                    .transfer(&env.current_contract_address(), &account, &amount);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn sweep(env: Env, to: Address) -> Result<i128, AirdropError> {
+        let config = read_config(&env)?;
+        config.admin.require_auth();
+
+        if env.ledger().timestamp() <= config.deadline {
+            return Err(AirdropError::ClaimWindowOpen);
+        }
+
+        let token = token::TokenClient::new(&env, &config.token);
+        let remaining = token.balance(&env.current_contract_address());
+        if remaining <= 0 {
+            return Err(AirdropError::NothingToSweep);
+        }
+
+        token.transfer(&env.current_contract_address(), &to, &remaining);
+
+        Ok(remaining)
+    }
+
+    fn is_claimed(env: Env, index: u32) -> bool {
+        is_claimed(&env, index)
+    }
+
+    fn config(env: Env) -> Result<AirdropConfig, AirdropError> {
+        read_config(&env)
+    }
+}
+
+/// Hoja del árbol: sha256(0x00 || index || address || amount)
+///
+/// Pública para que las herramientas off-chain (y los tests) construyan
+/// el árbol exactamente igual que el contrato.
+pub fn leaf_hash(env: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &[LEAF_PREFIX]);
+    data.extend_from_array(&index.to_be_bytes());
+    data.append(&account.clone().to_xdr(env));
+    data.extend_from_array(&amount.to_be_bytes());
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Combina un nodo con su hermano: sha256(0x01 || izquierdo || derecho);
+/// `node_is_right` indica el orden
+pub fn hash_pair(env: &Env, node: &BytesN<32>, sibling: &BytesN<32>, node_is_right: bool) -> BytesN<32> {
+    let (left, right) = if node_is_right { (sibling, node) } else { (node, sibling) };
+    let mut data = Bytes::from_array(env, &[NODE_PREFIX]);
+    data.append(&Bytes::from(left.clone()));
+    data.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+fn read_config(env: &Env) -> Result<AirdropConfig, AirdropError> {
+    env.storage().instance()
+        .get(&DataKey::Config)
+        .ok_or(AirdropError::NotInitialized)
+}
+
+fn is_claimed(env: &Env, index: u32) -> bool {
+    let word: u128 = env.storage().persistent()
+        .get(&DataKey::Claimed(index / BITS_PER_WORD))
+        .unwrap_or(0);
+    word & (1u128 << (index % BITS_PER_WORD)) != 0
+}
+
+fn set_claimed(env: &Env, index: u32) {
+    let key = DataKey::Claimed(index / BITS_PER_WORD);
+    let word: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(word | (1u128 << (index % BITS_PER_WORD))));
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,40 @@
+// src/storage.rs
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// Claves de almacenamiento del airdrop
+///
+/// - Instance Storage: configuración global
+/// - Persistent Storage: bitmap de hojas reclamadas
+#[contracttype]
+pub enum DataKey {
+    /// Configuración del airdrop - Instance Storage
+    Config,
+
+    /// Palabra del bitmap de reclamos - Persistent Storage
+    /// Cada entrada cubre 128 hojas (bit i = hoja word * 128 + i)
+    Claimed(u32),
+}
+
+/// Cómo se pagan los reclamos
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Payout {
+    /// El airdrop es admin del token y mintea cada reclamo
+    Mint,
+    /// El airdrop paga desde un pool de CODEBIT depositado en el contrato
+    Pool,
+}
+
+/// Configuración fijada en initialize()
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AirdropConfig {
+    pub admin: Address,
+    /// Contrato TokenCodebit
+    pub token: Address,
+    /// Raíz SHA-256 del árbol (index, address, amount)
+    pub merkle_root: BytesN<32>,
+    /// Timestamp después del cual no se aceptan reclamos
+    pub deadline: u64,
+    pub payout: Payout,
+}
@@ -0,0 +1,216 @@
+// src/test.rs
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+use token_codebit::{TokenCodebit, TokenCodebitClient};
+
+const DEADLINE: u64 = 1_000_000;
+
+/// Árbol Merkle de 4 contribuidores: (address, amount)
+struct Tree {
+    accounts: std::vec::Vec<(Address, i128)>,
+    leaves: std::vec::Vec<BytesN<32>>,
+    root: BytesN<32>,
+}
+
+impl Tree {
+    fn new(env: &Env, amounts: &[i128; 4]) -> Tree {
+        let accounts: std::vec::Vec<(Address, i128)> = amounts
+            .iter()
+            .map(|amount| (Address::generate(env), *amount))
+            .collect();
+        let leaves: std::vec::Vec<BytesN<32>> = accounts
+            .iter()
+            .enumerate()
+            .map(|(i, (account, amount))| leaf_hash(env, i as u32, account, *amount))
+            .collect();
+        let left = hash_pair(env, &leaves[0], &leaves[1], false);
+        let right = hash_pair(env, &leaves[2], &leaves[3], false);
+        let root = hash_pair(env, &left, &right, false);
+        Tree { accounts, leaves, root }
+    }
+
+    /// Prueba de la hoja `index`: hermano y luego el nodo del otro lado
+    fn proof(&self, env: &Env, index: usize) -> Vec<BytesN<32>> {
+        let sibling = self.leaves[index ^ 1].clone();
+        let (a, b) = if index < 2 { (2, 3) } else { (0, 1) };
+        let uncle = hash_pair(env, &self.leaves[a], &self.leaves[b], false);
+        vec![env, sibling, uncle]
+    }
+}
+
+/// Helper: CODEBIT + airdrop en modo Pool con el pool fondeado
+fn setup_pool<'a>(
+    env: &Env,
+    tree: &Tree,
+    pool: i128
+) -> (TokenCodebitClient<'a>, CodebitAirdropClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let token_id = env.register(TokenCodebit, ());
+    let token = TokenCodebitClient::new(env, &token_id);
+    token.initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    );
+
+    let airdrop_id = env.register(CodebitAirdrop, ());
+    let airdrop = CodebitAirdropClient::new(env, &airdrop_id);
+    airdrop.initialize(&admin, &token_id, &tree.root, &DEADLINE, &Payout::Pool);
+    token.mint(&airdrop_id, &pool);
+
+    (token, airdrop, admin)
+}
+
+// ============================================================================
+// 1️⃣ TESTS DE RECLAMOS
+// ============================================================================
+
+/// Test: cada contribuidor reclama una sola vez desde el pool
+#[test]
+fn test_claim_from_pool() {
+    let env = Env::default();
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+    let (token, airdrop, _) = setup_pool(&env, &tree, 990);
+
+    for (i, (account, amount)) in tree.accounts.iter().enumerate() {
+        airdrop.claim(account, &(i as u32), amount, &tree.proof(&env, i));
+        assert_eq!(token.balance(account), *amount);
+        assert!(airdrop.is_claimed(&(i as u32)));
+    }
+    assert_eq!(token.balance(&airdrop.address), 0);
+
+    // Segundo reclamo de la misma hoja
+    let (bob, amount) = &tree.accounts[1];
+    let result = airdrop.try_claim(bob, &1, amount, &tree.proof(&env, 1));
+    assert_eq!(result, Err(Ok(AirdropError::AlreadyClaimed)));
+}
+
+/// Test: pruebas o montos alterados no pasan la verificación
+#[test]
+fn test_invalid_proofs() {
+    let env = Env::default();
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+    let (token, airdrop, _) = setup_pool(&env, &tree, 990);
+    let (alice, amount) = &tree.accounts[0];
+
+    // Monto inflado
+    let result = airdrop.try_claim(alice, &0, &(amount + 1), &tree.proof(&env, 0));
+    assert_eq!(result, Err(Ok(AirdropError::InvalidProof)));
+
+    // Prueba de otra hoja
+    let result = airdrop.try_claim(alice, &0, amount, &tree.proof(&env, 2));
+    assert_eq!(result, Err(Ok(AirdropError::InvalidProof)));
+
+    // Otra cuenta usando la hoja de Alice
+    let mallory = Address::generate(&env);
+    let result = airdrop.try_claim(&mallory, &0, amount, &tree.proof(&env, 0));
+    assert_eq!(result, Err(Ok(AirdropError::InvalidProof)));
+
+    assert!(!airdrop.is_claimed(&0));
+    assert_eq!(token.balance(&airdrop.address), 990);
+}
+
+/// Test: en modo Mint el airdrop es el admin del token
+#[test]
+fn test_claim_with_mint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+
+    let airdrop_id = env.register(CodebitAirdrop, ());
+    let airdrop = CodebitAirdropClient::new(&env, &airdrop_id);
+    let token_id = env.register(TokenCodebit, ());
+    let token = TokenCodebitClient::new(&env, &token_id);
+    token.initialize(
+        &airdrop_id,
+        &String::from_str(&env, "Codebit"),
+        &String::from_str(&env, "CODE"),
+        &0
+    );
+    let admin = Address::generate(&env);
+    airdrop.initialize(&admin, &token_id, &tree.root, &DEADLINE, &Payout::Mint);
+
+    let (dave, amount) = &tree.accounts[3];
+    airdrop.claim(dave, &3, amount, &tree.proof(&env, 3));
+    assert_eq!(token.balance(dave), 600);
+    assert_eq!(token.total_supply(), 600);
+}
+
+// ============================================================================
+// 2️⃣ TESTS DE DEADLINE Y SWEEP
+// ============================================================================
+
+/// Test: después del deadline no hay reclamos y el admin barre el resto
+#[test]
+fn test_sweep_after_deadline() {
+    let env = Env::default();
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+    let (token, airdrop, admin) = setup_pool(&env, &tree, 990);
+
+    let (alice, amount) = &tree.accounts[0];
+    airdrop.claim(alice, &0, amount, &tree.proof(&env, 0));
+
+    // Antes del deadline no se puede barrer
+    assert_eq!(airdrop.try_sweep(&admin), Err(Ok(AirdropError::ClaimWindowOpen)));
+
+    env.ledger().set_timestamp(DEADLINE + 1);
+    let (bob, amount) = &tree.accounts[1];
+    let result = airdrop.try_claim(bob, &1, amount, &tree.proof(&env, 1));
+    assert_eq!(result, Err(Ok(AirdropError::ClaimWindowClosed)));
+
+    assert_eq!(airdrop.sweep(&admin), 890);
+    assert_eq!(token.balance(&admin), 890);
+    assert_eq!(airdrop.try_sweep(&admin), Err(Ok(AirdropError::NothingToSweep)));
+}
+
+/// Test: el deadline tiene que quedar en el futuro
+#[test]
+fn test_initialize_rejects_past_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(DEADLINE);
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+
+    let airdrop = CodebitAirdropClient::new(&env, &env.register(CodebitAirdrop, ()));
+    let (admin, token) = (Address::generate(&env), Address::generate(&env));
+
+    let result = airdrop.try_initialize(&admin, &token, &tree.root, &DEADLINE, &Payout::Pool);
+    assert_eq!(result, Err(Ok(AirdropError::InvalidDeadline)));
+    airdrop.initialize(&admin, &token, &tree.root, &(DEADLINE + 1), &Payout::Pool);
+}
+
+// ============================================================================
+// 3️⃣ TESTS DEL ÁRBOL
+// ============================================================================
+
+/// Test: hojas y nodos usan prefijos distintos, así una hoja de 64 bytes
+/// nunca se confunde con un nodo interno
+#[test]
+fn test_leaves_and_nodes_are_domain_separated() {
+    let env = Env::default();
+    let tree = Tree::new(&env, &[100, 250, 40, 600]);
+    let (alice, amount) = &tree.accounts[0];
+
+    let mut leaf = Bytes::from_array(&env, &[0x00]);
+    leaf.extend_from_array(&0u32.to_be_bytes());
+    leaf.append(&alice.clone().to_xdr(&env));
+    leaf.extend_from_array(&amount.to_be_bytes());
+    assert_eq!(tree.leaves[0], env.crypto().sha256(&leaf).to_bytes());
+
+    let mut node = Bytes::from_array(&env, &[0x01]);
+    node.append(&Bytes::from(tree.leaves[0].clone()));
+    node.append(&Bytes::from(tree.leaves[1].clone()));
+    assert_eq!(
+        hash_pair(&env, &tree.leaves[0], &tree.leaves[1], false),
+        env.crypto().sha256(&node).to_bytes()
+    );
+}
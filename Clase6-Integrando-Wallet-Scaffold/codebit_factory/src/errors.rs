@@ -0,0 +1,18 @@
+// src/errors.rs
+use soroban_sdk::contracterror;
+
+/// Errores de la fábrica de tokens CODEBIT
+/// Los códigos empiezan en 1 (0 está reservado para "sin error")
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    /// La fábrica ya fue inicializada
+    AlreadyInitialized = 1,
+
+    /// Falta llamar initialize()
+    NotInitialized = 2,
+
+    /// El admin ya usó este salt (la address del token ya existe)
+    SaltAlreadyUsed = 3,
+}
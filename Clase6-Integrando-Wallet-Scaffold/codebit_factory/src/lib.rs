@@ -0,0 +1,173 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec
+};
+
+mod storage;
+mod errors;
+
+pub use storage::{FactoryConfig, TokenInfo};
+use storage::DataKey;
+use errors::FactoryError;
+
+/// Máximo de entradas por página del registro
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Regla de DevPoints: CODEBIT siempre tiene 0 decimales
+const CODEBIT_DECIMALS: u32 = 0;
+
+/// Interfaz mínima de TokenCodebit para inicializar instancias nuevas
+#[contractclient(name = "CodebitInitClient")]
+pub trait CodebitInit {
+    fn initialize(env: Env, admin: Address, name: String, symbol: String, decimals: u32);
+}
+
+/// Trait de la fábrica de tokens CODEBIT
+///
+/// Cada comunidad hermana obtiene su propia instancia de TokenCodebit
+/// con las mismas reglas. La address depende solo de (admin, salt), así
+/// que se puede calcular antes de desplegar con `token_address`.
+pub trait FactoryTrait {
+    /// Configura owner y wasm hash de TokenCodebit (una sola vez)
+    fn initialize(env: Env, owner: Address, wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Despliega e inicializa un token nuevo en la misma transacción
+    fn deploy_token(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        salt: BytesN<32>
+    ) -> Result<Address, FactoryError>;
+
+    /// El owner cambia el wasm usado en despliegues futuros
+    fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    // Métodos de consulta
+    fn token_address(env: Env, admin: Address, salt: BytesN<32>) -> Address;
+    fn tokens(env: Env, start: u32, limit: u32) -> Vec<TokenInfo>;
+    fn token_count(env: Env) -> u32;
+    fn config(env: Env) -> Result<FactoryConfig, FactoryError>;
+}
+
+#[contract]
+pub struct CodebitFactory;
+
+#[contractimpl]
+impl FactoryTrait for CodebitFactory {
+    fn initialize(env: Env, owner: Address, wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(FactoryError::AlreadyInitialized);
+        }
+
+        owner.require_auth();
+
+        env.storage().instance().set(&DataKey::Config, &FactoryConfig { owner, wasm_hash });
+        env.storage().instance().extend_ttl(100_000, 200_000);
+
+        Ok(())
+    }
+
+    fn deploy_token(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        salt: BytesN<32>
+    ) -> Result<Address, FactoryError> {
+        let config = read_config(&env)?;
+
+        // 1. El admin del token nuevo autoriza el despliegue
+        admin.require_auth();
+
+        // 2. Desplegar con salt determinístico
+        let deployer = env.deployer().with_current_contract(admin_salt(&env, &admin, &salt));
+        let address = deployer.deployed_address();
+        if env.storage().persistent().has(&DataKey::Deployed(address.clone())) {
+            return Err(FactoryError::SaltAlreadyUsed);
+        }
+        let address = deployer.deploy_v2(config.wasm_hash, ());
+
+        // 3. Inicializar en la misma transacción: si falla, se revierte todo
+        CodebitInitClient::new(&env, &address)
+            .initialize(&admin, &name, &symbol, &CODEBIT_DECIMALS);
+
+        // 4. Registrar
+        let index: u32 = env.storage().instance()
+            .get(&DataKey::TokenCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::TokenCount, &(index + 1));
+
+        let key = DataKey::Token(index);
+        let info = TokenInfo { address: address.clone(), name, symbol, admin };
+        env.storage().persistent().set(&key, &info);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+
+        let key = DataKey::Deployed(address.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+
+        Ok(address)
+    }
+
+    fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+        let mut config = read_config(&env)?;
+        config.owner.require_auth();
+
+        config.wasm_hash = wasm_hash;
+        env.storage().instance().set(&DataKey::Config, &config);
+
+        Ok(())
+    }
+
+    fn token_address(env: Env, admin: Address, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(admin_salt(&env, &admin, &salt))
+            .deployed_address()
+    }
+
+    fn tokens(env: Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+        let count = read_token_count(&env);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            if let Some(info) = env.storage().persistent().get(&DataKey::Token(index)) {
+                page.push_back(info);
+            }
+        }
+        page
+    }
+
+    fn token_count(env: Env) -> u32 {
+        read_token_count(&env)
+    }
+
+    fn config(env: Env) -> Result<FactoryConfig, FactoryError> {
+        read_config(&env)
+    }
+}
+
+/// Salt efectivo: sha256(admin || salt)
+///
+/// Incluir al admin evita que otro despliegue primero en la address
+/// que una comunidad ya anunció.
+fn admin_salt(env: &Env, admin: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data = admin.clone().to_xdr(env);
+    data.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+fn read_config(env: &Env) -> Result<FactoryConfig, FactoryError> {
+    env.storage().instance()
+        .get(&DataKey::Config)
+        .ok_or(FactoryError::NotInitialized)
+}
+
+fn read_token_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::TokenCount).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,40 @@
+// src/storage.rs
+use soroban_sdk::{contracttype, Address, BytesN, String};
+
+/// Claves de almacenamiento de la fábrica
+///
+/// - Instance Storage: owner, wasm hash y contador
+/// - Persistent Storage: registro de tokens desplegados
+#[contracttype]
+pub enum DataKey {
+    /// Configuración de la fábrica - Instance Storage
+    Config,
+
+    /// Cantidad de tokens desplegados - Instance Storage
+    TokenCount,
+
+    /// Token desplegado por posición - Persistent Storage
+    Token(u32),
+
+    /// Address ya desplegada por la fábrica - Persistent Storage
+    Deployed(Address),
+}
+
+/// Configuración de la fábrica
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactoryConfig {
+    pub owner: Address,
+    /// Hash del wasm de TokenCodebit subido a la red
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Entrada del registro de tokens desplegados
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub address: Address,
+    pub name: String,
+    pub symbol: String,
+    pub admin: Address,
+}
@@ -0,0 +1,158 @@
+// src/test.rs
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+/// Wasm de TokenCodebit versionado en wasm/ (ver README para regenerarlo)
+mod codebit {
+    soroban_sdk::contractimport!(file = "wasm/token_codebit.wasm");
+
+    /// TokenCodebit declara `TokenError` pero firma sus funciones con el alias `CodebitError`
+    #[allow(dead_code)]
+    pub type CodebitError = TokenError;
+}
+
+/// Helper: fábrica inicializada con el wasm de TokenCodebit subido
+fn setup_factory<'a>(env: &Env) -> (CodebitFactoryClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let owner = Address::generate(env);
+    let wasm_hash = env.deployer().upload_contract_wasm(codebit::WASM);
+
+    let factory_id = env.register(CodebitFactory, ());
+    let factory = CodebitFactoryClient::new(env, &factory_id);
+    factory.initialize(&owner, &wasm_hash);
+
+    (factory, owner)
+}
+
+// ============================================================================
+// 1️⃣ TESTS DE DESPLIEGUE
+// ============================================================================
+
+/// Test: la fábrica despliega tokens inicializados en addresses predecibles
+#[test]
+fn test_deploy_token() {
+    let env = Env::default();
+    let (factory, _) = setup_factory(&env);
+    let admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[1; 32]);
+
+    let predicted = factory.token_address(&admin, &salt);
+    let address = factory.deploy_token(
+        &admin,
+        &String::from_str(&env, "Tiburona Credits"),
+        &String::from_str(&env, "TIBU"),
+        &salt
+    );
+    assert_eq!(address, predicted);
+
+    let token = codebit::Client::new(&env, &address);
+    assert_eq!(token.name(), String::from_str(&env, "Tiburona Credits"));
+    assert_eq!(token.symbol(), String::from_str(&env, "TIBU"));
+    assert_eq!(token.decimals(), 0);
+    assert_eq!(token.admin(), admin);
+
+    // El admin de la comunidad ya puede mintear
+    let dev = Address::generate(&env);
+    token.mint(&dev, &10);
+    assert_eq!(token.balance(&dev), 10);
+
+    // El mismo salt no se reusa, pero otro admin obtiene otra address
+    let result = factory.try_deploy_token(
+        &admin,
+        &String::from_str(&env, "Otra"),
+        &String::from_str(&env, "OTRA"),
+        &salt
+    );
+    assert_eq!(result, Err(Ok(FactoryError::SaltAlreadyUsed)));
+    assert_ne!(factory.token_address(&Address::generate(&env), &salt), address);
+}
+
+/// Test: si la inicialización falla no queda nada desplegado ni registrado
+#[test]
+fn test_deploy_is_atomic() {
+    let env = Env::default();
+    let (factory, _) = setup_factory(&env);
+    let admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[2; 32]);
+
+    // Nombre vacío: TokenCodebit rechaza la metadata
+    let result = factory.try_deploy_token(
+        &admin,
+        &String::from_str(&env, ""),
+        &String::from_str(&env, "TIBU"),
+        &salt
+    );
+    assert!(result.is_err());
+    assert_eq!(factory.token_count(), 0);
+
+    // El salt sigue disponible
+    factory.deploy_token(
+        &admin,
+        &String::from_str(&env, "Tiburona Credits"),
+        &String::from_str(&env, "TIBU"),
+        &salt
+    );
+    assert_eq!(factory.token_count(), 1);
+}
+
+// ============================================================================
+// 2️⃣ TESTS DE REGISTRO Y WASM HASH
+// ============================================================================
+
+/// Test: el registro se consulta paginado
+#[test]
+fn test_registry_pagination() {
+    let env = Env::default();
+    let (factory, _) = setup_factory(&env);
+    let symbols = ["AAA", "BBB", "CCC"];
+
+    for (i, symbol) in symbols.iter().enumerate() {
+        factory.deploy_token(
+            &Address::generate(&env),
+            &String::from_str(&env, "Comunidad"),
+            &String::from_str(&env, symbol),
+            &BytesN::from_array(&env, &[i as u8; 32])
+        );
+    }
+    assert_eq!(factory.token_count(), 3);
+
+    let page = factory.tokens(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().symbol, String::from_str(&env, "AAA"));
+    assert_eq!(page.get(1).unwrap().symbol, String::from_str(&env, "BBB"));
+
+    let page = factory.tokens(&2, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().symbol, String::from_str(&env, "CCC"));
+
+    assert_eq!(factory.tokens(&5, &2).len(), 0);
+}
+
+/// Test: el owner cambia el wasm para despliegues futuros
+#[test]
+fn test_set_wasm_hash() {
+    let env = Env::default();
+    let (factory, owner) = setup_factory(&env);
+
+    let new_hash = BytesN::from_array(&env, &[9; 32]);
+    factory.set_wasm_hash(&new_hash);
+    assert_eq!(factory.config(), FactoryConfig { owner, wasm_hash: new_hash.clone() });
+
+    // Un hash que no fue subido no se puede desplegar
+    let result = factory.try_deploy_token(
+        &Address::generate(&env),
+        &String::from_str(&env, "Comunidad"),
+        &String::from_str(&env, "COM"),
+        &BytesN::from_array(&env, &[3; 32])
+    );
+    assert!(result.is_err());
+    assert_eq!(factory.token_count(), 0);
+
+    assert_eq!(
+        factory.try_initialize(&Address::generate(&env), &new_hash),
+        Err(Ok(FactoryError::AlreadyInitialized))
+    );
+}
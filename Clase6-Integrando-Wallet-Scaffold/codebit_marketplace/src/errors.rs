@@ -0,0 +1,45 @@
+// src/errors.rs
+use soroban_sdk::contracterror;
+
+/// Errores del marketplace
+/// Los códigos empiezan en 1 (0 está reservado para "sin error")
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MarketError {
+    /// El marketplace ya fue inicializado
+    AlreadyInitialized = 1,
+
+    /// Falta llamar initialize()
+    NotInitialized = 2,
+
+    /// Precio debe ser > 0
+    InvalidAmount = 3,
+
+    /// Capacidad debe ser > 0
+    InvalidCapacity = 4,
+
+    /// Descripción vacía o demasiado larga
+    InvalidDescription = 5,
+
+    /// La publicación solicitada no existe
+    ListingNotFound = 6,
+
+    /// La publicación fue cerrada por el proveedor
+    ListingClosed = 7,
+
+    /// No quedan cupos en la publicación
+    SoldOut = 8,
+
+    /// La orden solicitada no existe
+    OrderNotFound = 9,
+
+    /// La orden no está en el estado requerido por la operación
+    InvalidStatus = 10,
+
+    /// El llamador no es parte de la orden (o es el propio proveedor)
+    Unauthorized = 11,
+
+    /// transfer_from falló (allowance o balance insuficiente)
+    PaymentFailed = 12,
+}
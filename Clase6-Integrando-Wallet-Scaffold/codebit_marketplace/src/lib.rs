@@ -0,0 +1,341 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String, Vec};
+
+mod storage;
+mod errors;
+
+pub use storage::{Listing, MarketConfig, Order, OrderStatus};
+use storage::DataKey;
+use errors::MarketError;
+
+/// Largo máximo de la descripción de un servicio
+const MAX_DESCRIPTION_LENGTH: u32 = 280;
+
+/// Máximo de publicaciones por página
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Trait del marketplace de servicios pagados en CODEBIT
+///
+/// Flujo de una orden:
+/// Paid -> Delivered -> Completed (el comprador confirma)
+/// Paid | Delivered -> Disputed -> Completed | Refunded (decide el árbitro)
+pub trait MarketplaceTrait {
+    /// Configura token y árbitro (una sola vez)
+    fn initialize(env: Env, admin: Address, token: Address, arbiter: Address) -> Result<(), MarketError>;
+
+    /// El admin reemplaza al árbitro
+    fn set_arbiter(env: Env, arbiter: Address) -> Result<(), MarketError>;
+
+    /// Un proveedor publica un servicio
+    fn create_listing(
+        env: Env,
+        provider: Address,
+        description: String,
+        price: i128,
+        capacity: u32
+    ) -> Result<u64, MarketError>;
+
+    /// El proveedor deja de vender cupos (las órdenes abiertas siguen)
+    fn close_listing(env: Env, listing_id: u64) -> Result<(), MarketError>;
+
+    /// El comprador paga un cupo al escrow vía transfer_from
+    /// (antes debe aprobar al marketplace en el token)
+    fn purchase(env: Env, buyer: Address, listing_id: u64) -> Result<u64, MarketError>;
+
+    /// El proveedor marca el servicio como entregado
+    fn mark_delivered(env: Env, order_id: u64) -> Result<(), MarketError>;
+
+    /// El comprador confirma la entrega y libera el pago al proveedor
+    fn confirm_delivery(env: Env, order_id: u64) -> Result<(), MarketError>;
+
+    /// Comprador o proveedor abren una disputa
+    fn open_dispute(env: Env, caller: Address, order_id: u64) -> Result<(), MarketError>;
+
+    /// El árbitro decide: reembolso al comprador o pago al proveedor
+    fn resolve_dispute(env: Env, order_id: u64, refund_buyer: bool) -> Result<(), MarketError>;
+
+    // Métodos de consulta
+    fn listings(env: Env, start: u64, limit: u32) -> Vec<Listing>;
+    fn listing_count(env: Env) -> u64;
+    fn get_listing(env: Env, listing_id: u64) -> Result<Listing, MarketError>;
+    fn get_order(env: Env, order_id: u64) -> Result<Order, MarketError>;
+}
+
+#[contract]
+pub struct CodebitMarketplace;
+
+#[contractimpl]
+impl MarketplaceTrait for CodebitMarketplace {
+    fn initialize(env: Env, admin: Address, token: Address, arbiter: Address) -> Result<(), MarketError> {
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(MarketError::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Config, &MarketConfig { admin, token, arbiter });
+        env.storage().instance().extend_ttl(100_000, 200_000);
+
+        Ok(())
+    }
+
+    fn set_arbiter(env: Env, arbiter: Address) -> Result<(), MarketError> {
+        let mut config = read_config(&env)?;
+        config.admin.require_auth();
+
+        config.arbiter = arbiter;
+        env.storage().instance().set(&DataKey::Config, &config);
+
+        Ok(())
+    }
+
+    fn create_listing(
+        env: Env,
+        provider: Address,
+        description: String,
+        price: i128,
+        capacity: u32
+    ) -> Result<u64, MarketError> {
+        read_config(&env)?;
+
+        // 1. Solo el proveedor publica sus servicios
+        provider.require_auth();
+
+        // 2. Validaciones
+        if price <= 0 {
+            return Err(MarketError::InvalidAmount);
+        }
+
+        if capacity == 0 {
+            return Err(MarketError::InvalidCapacity);
+        }
+
+        if description.is_empty() || description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(MarketError::InvalidDescription);
+        }
+
+        // 3. Guardar con id incremental
+        let listing_id: u64 = env.storage().instance()
+            .get(&DataKey::ListingCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::ListingCount, &(listing_id + 1));
+
+        write_listing(&env, &Listing {
+            id: listing_id,
+            provider,
+            description,
+            price,
+            capacity,
+            sold: 0,
+            active: true,
+        });
+
+        Ok(listing_id)
+    }
+
+    fn close_listing(env: Env, listing_id: u64) -> Result<(), MarketError> {
+        let mut listing = read_listing(&env, listing_id)?;
+        listing.provider.require_auth();
+
+        listing.active = false;
+        write_listing(&env, &listing);
+
+        Ok(())
+    }
+
+    fn purchase(env: Env, buyer: Address, listing_id: u64) -> Result<u64, MarketError> {
+        let config = read_config(&env)?;
+
+        // 1. El comprador autoriza la compra
+        buyer.require_auth();
+
+        // 2. Validaciones
+        let mut listing = read_listing(&env, listing_id)?;
+        if !listing.active {
+            return Err(MarketError::ListingClosed);
+        }
+
+        if listing.sold >= listing.capacity {
+            return Err(MarketError::SoldOut);
+        }
+
+        if buyer == listing.provider {
+            return Err(MarketError::Unauthorized);
+        }
+
+        // 3. Pull payment al escrow: este contrato es el spender aprobado
+        let payment = token::TokenClient::new(&env, &config.token).try_transfer_from(
+            &env.current_contract_address(),
+            &buyer,
+            &env.current_contract_address(),
+            &listing.price
+        );
+        if !matches!(payment, Ok(Ok(()))) {
+            return Err(MarketError::PaymentFailed);
+        }
+
+        // 4. Ocupar el cupo y crear la orden
+        listing.sold += 1;
+        write_listing(&env, &listing);
+
+        let order_id: u64 = env.storage().instance()
+            .get(&DataKey::OrderCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::OrderCount, &(order_id + 1));
+
+        write_order(&env, order_id, &Order {
+            listing_id,
+            buyer,
+            provider: listing.provider,
+            amount: listing.price,
+            status: OrderStatus::Paid,
+        });
+
+        Ok(order_id)
+    }
+
+    fn mark_delivered(env: Env, order_id: u64) -> Result<(), MarketError> {
+        let mut order = read_order(&env, order_id)?;
+        order.provider.require_auth();
+
+        if order.status != OrderStatus::Paid {
+            return Err(MarketError::InvalidStatus);
+        }
+
+        order.status = OrderStatus::Delivered;
+        write_order(&env, order_id, &order);
+
+        Ok(())
+    }
+
+    fn confirm_delivery(env: Env, order_id: u64) -> Result<(), MarketError> {
+        let config = read_config(&env)?;
+        let mut order = read_order(&env, order_id)?;
+        order.buyer.require_auth();
+
+        if order.status != OrderStatus::Delivered {
+            return Err(MarketError::InvalidStatus);
+        }
+
+        order.status = OrderStatus::Completed;
+        write_order(&env, order_id, &order);
+        release_escrow(&env, &config, &order.provider, order.amount);
+
+        Ok(())
+    }
+
+    fn open_dispute(env: Env, caller: Address, order_id: u64) -> Result<(), MarketError> {
+        let mut order = read_order(&env, order_id)?;
+
+        // Cualquiera de las dos partes puede disputar
+        caller.require_auth();
+        if caller != order.buyer && caller != order.provider {
+            return Err(MarketError::Unauthorized);
+        }
+
+        if order.status != OrderStatus::Paid && order.status != OrderStatus::Delivered {
+            return Err(MarketError::InvalidStatus);
+        }
+
+        order.status = OrderStatus::Disputed;
+        write_order(&env, order_id, &order);
+
+        Ok(())
+    }
+
+    fn resolve_dispute(env: Env, order_id: u64, refund_buyer: bool) -> Result<(), MarketError> {
+        let config = read_config(&env)?;
+        config.arbiter.require_auth();
+
+        let mut order = read_order(&env, order_id)?;
+        if order.status != OrderStatus::Disputed {
+            return Err(MarketError::InvalidStatus);
+        }
+
+        if refund_buyer {
+            // El cupo reembolsado vuelve a estar disponible
+            let mut listing = read_listing(&env, order.listing_id)?;
+            listing.sold -= 1;
+            write_listing(&env, &listing);
+
+            order.status = OrderStatus::Refunded;
+            release_escrow(&env, &config, &order.buyer, order.amount);
+        } else {
+            order.status = OrderStatus::Completed;
+            release_escrow(&env, &config, &order.provider, order.amount);
+        }
+        write_order(&env, order_id, &order);
+
+        Ok(())
+    }
+
+    // Métodos de consulta
+    fn listings(env: Env, start: u64, limit: u32) -> Vec<Listing> {
+        let count = read_listing_count(&env);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE) as u64).min(count);
+
+        let mut page = Vec::new(&env);
+        for listing_id in start..end {
+            if let Ok(listing) = read_listing(&env, listing_id) {
+                page.push_back(listing);
+            }
+        }
+        page
+    }
+
+    fn listing_count(env: Env) -> u64 {
+        read_listing_count(&env)
+    }
+
+    fn get_listing(env: Env, listing_id: u64) -> Result<Listing, MarketError> {
+        read_listing(&env, listing_id)
+    }
+
+    fn get_order(env: Env, order_id: u64) -> Result<Order, MarketError> {
+        read_order(&env, order_id)
+    }
+}
+
+/// Paga desde el escrow del contrato
+fn release_escrow(env: &Env, config: &MarketConfig, to: &Address, amount: i128) {
+    token::TokenClient::new(env, &config.token)
+        .transfer(&env.current_contract_address(), to, &amount);
+}
+
+fn read_config(env: &Env) -> Result<MarketConfig, MarketError> {
+    env.storage().instance()
+        .get(&DataKey::Config)
+        .ok_or(MarketError::NotInitialized)
+}
+
+fn read_listing_count(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::ListingCount).unwrap_or(0)
+}
+
+fn read_listing(env: &Env, listing_id: u64) -> Result<Listing, MarketError> {
+    env.storage().persistent()
+        .get(&DataKey::Listing(listing_id))
+        .ok_or(MarketError::ListingNotFound)
+}
+
+fn write_listing(env: &Env, listing: &Listing) {
+    let key = DataKey::Listing(listing.id);
+    env.storage().persistent().set(&key, listing);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
+
+fn read_order(env: &Env, order_id: u64) -> Result<Order, MarketError> {
+    env.storage().persistent()
+        .get(&DataKey::Order(order_id))
+        .ok_or(MarketError::OrderNotFound)
+}
+
+fn write_order(env: &Env, order_id: u64, order: &Order) {
+    let key = DataKey::Order(order_id);
+    env.storage().persistent().set(&key, order);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
+
+#[cfg(test)]
+mod test;
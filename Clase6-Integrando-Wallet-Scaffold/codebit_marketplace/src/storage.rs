@@ -0,0 +1,79 @@
+// src/storage.rs
+use soroban_sdk::{contracttype, Address, String};
+
+/// Claves de almacenamiento del marketplace
+///
+/// - Instance Storage: configuración y contadores globales
+/// - Persistent Storage: publicaciones y órdenes (requieren TTL)
+#[contracttype]
+pub enum DataKey {
+    /// Configuración del marketplace - Instance Storage
+    Config,
+
+    /// Publicación de un proveedor - Persistent Storage
+    Listing(u64),
+
+    /// Contador de publicaciones creadas - Instance Storage
+    ListingCount,
+
+    /// Orden de compra de un cupo - Persistent Storage
+    Order(u64),
+
+    /// Contador de órdenes creadas - Instance Storage
+    OrderCount,
+}
+
+/// Configuración fijada en initialize()
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketConfig {
+    pub admin: Address,
+    /// Token SEP-41 en el que se pagan los servicios (CODEBIT)
+    pub token: Address,
+    /// Quien resuelve las disputas
+    pub arbiter: Address,
+}
+
+/// Servicio ofrecido por un proveedor (ej: "code review de 30 min")
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub id: u64,
+    pub provider: Address,
+    pub description: String,
+    /// Precio por cupo en minutos CODEBIT
+    pub price: i128,
+    /// Cupos totales
+    pub capacity: u32,
+    /// Cupos vendidos (los reembolsados se liberan)
+    pub sold: u32,
+    pub active: bool,
+}
+
+/// Estado de una orden
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrderStatus {
+    /// Pago en escrow, esperando la entrega
+    Paid,
+    /// El proveedor marcó el servicio como entregado
+    Delivered,
+    /// Una de las partes abrió una disputa
+    Disputed,
+    /// El proveedor cobró el escrow
+    Completed,
+    /// El comprador recuperó el escrow
+    Refunded,
+}
+
+/// Compra de un cupo de una publicación
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub listing_id: u64,
+    pub buyer: Address,
+    pub provider: Address,
+    /// Monto en escrow (precio al momento de la compra)
+    pub amount: i128,
+    pub status: OrderStatus,
+}
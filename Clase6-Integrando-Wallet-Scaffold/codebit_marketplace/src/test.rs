@@ -0,0 +1,162 @@
+// src/test.rs
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use token_codebit::{TokenCodebit, TokenCodebitClient};
+
+/// Helper: CODEBIT + marketplace, con saldo y approve del comprador
+fn setup<'a>(
+    env: &Env,
+    buyer: &Address
+) -> (TokenCodebitClient<'a>, CodebitMarketplaceClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let token = TokenCodebitClient::new(env, &env.register(TokenCodebit, ()));
+    token.initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    );
+    token.mint(buyer, &200);
+
+    let arbiter = Address::generate(env);
+    let market = CodebitMarketplaceClient::new(env, &env.register(CodebitMarketplace, ()));
+    market.initialize(&admin, &token.address, &arbiter);
+    token.approve(buyer, &market.address, &200);
+
+    (token, market, arbiter)
+}
+
+fn review_listing(env: &Env, market: &CodebitMarketplaceClient, provider: &Address, capacity: u32) -> u64 {
+    market.create_listing(
+        provider,
+        &String::from_str(env, "Code review de 30 minutos"),
+        &30,
+        &capacity
+    )
+}
+
+// ============================================================================
+// 1️⃣ TESTS DE COMPRA Y ENTREGA
+// ============================================================================
+
+/// Test: el pago queda en escrow hasta que el comprador confirma
+#[test]
+fn test_purchase_deliver_confirm() {
+    let env = Env::default();
+    let (buyer, provider) = (Address::generate(&env), Address::generate(&env));
+    let (token, market, _) = setup(&env, &buyer);
+    let listing_id = review_listing(&env, &market, &provider, 2);
+
+    let order_id = market.purchase(&buyer, &listing_id);
+    assert_eq!(token.balance(&buyer), 170);
+    assert_eq!(token.balance(&market.address), 30);
+    assert_eq!(market.get_listing(&listing_id).sold, 1);
+
+    // No se confirma antes de la entrega
+    assert_eq!(market.try_confirm_delivery(&order_id), Err(Ok(MarketError::InvalidStatus)));
+
+    market.mark_delivered(&order_id);
+    market.confirm_delivery(&order_id);
+    assert_eq!(token.balance(&provider), 30);
+    assert_eq!(token.balance(&market.address), 0);
+    assert_eq!(market.get_order(&order_id).status, OrderStatus::Completed);
+
+    // Ya no se puede disputar una orden completada
+    let result = market.try_open_dispute(&buyer, &order_id);
+    assert_eq!(result, Err(Ok(MarketError::InvalidStatus)));
+}
+
+/// Test: capacidad, cierre y validaciones de compra
+#[test]
+fn test_purchase_limits() {
+    let env = Env::default();
+    let (buyer, provider) = (Address::generate(&env), Address::generate(&env));
+    let (token, market, _) = setup(&env, &buyer);
+    let listing_id = review_listing(&env, &market, &provider, 1);
+
+    // El proveedor no se compra a sí mismo
+    assert_eq!(market.try_purchase(&provider, &listing_id), Err(Ok(MarketError::Unauthorized)));
+
+    market.purchase(&buyer, &listing_id);
+    assert_eq!(market.try_purchase(&buyer, &listing_id), Err(Ok(MarketError::SoldOut)));
+
+    // Sin allowance el pago falla
+    let other = Address::generate(&env);
+    let second = review_listing(&env, &market, &provider, 5);
+    token.mint(&other, &100);
+    assert_eq!(market.try_purchase(&other, &second), Err(Ok(MarketError::PaymentFailed)));
+
+    market.close_listing(&second);
+    assert_eq!(market.try_purchase(&buyer, &second), Err(Ok(MarketError::ListingClosed)));
+
+    let result = market.try_create_listing(&provider, &String::from_str(&env, ""), &30, &1);
+    assert_eq!(result, Err(Ok(MarketError::InvalidDescription)));
+    let result = market.try_create_listing(&provider, &String::from_str(&env, "Pair"), &0, &1);
+    assert_eq!(result, Err(Ok(MarketError::InvalidAmount)));
+}
+
+// ============================================================================
+// 2️⃣ TESTS DE DISPUTAS
+// ============================================================================
+
+/// Test: el árbitro reembolsa al comprador o paga al proveedor
+#[test]
+fn test_disputes() {
+    let env = Env::default();
+    let (buyer, provider) = (Address::generate(&env), Address::generate(&env));
+    let (token, market, _) = setup(&env, &buyer);
+    let listing_id = review_listing(&env, &market, &provider, 2);
+
+    // Reembolso: el cupo vuelve a estar disponible
+    let refunded = market.purchase(&buyer, &listing_id);
+    assert_eq!(market.try_resolve_dispute(&refunded, &true), Err(Ok(MarketError::InvalidStatus)));
+    market.open_dispute(&buyer, &refunded);
+    market.resolve_dispute(&refunded, &true);
+    assert_eq!(token.balance(&buyer), 200);
+    assert_eq!(market.get_order(&refunded).status, OrderStatus::Refunded);
+    assert_eq!(market.get_listing(&listing_id).sold, 0);
+
+    // El proveedor entregó pero el comprador no confirma: disputa a favor del proveedor
+    let paid = market.purchase(&buyer, &listing_id);
+    market.mark_delivered(&paid);
+    market.open_dispute(&provider, &paid);
+    market.resolve_dispute(&paid, &false);
+    assert_eq!(token.balance(&provider), 30);
+    assert_eq!(market.get_order(&paid).status, OrderStatus::Completed);
+
+    // Terceros no abren disputas
+    let order_id = market.purchase(&buyer, &listing_id);
+    let result = market.try_open_dispute(&Address::generate(&env), &order_id);
+    assert_eq!(result, Err(Ok(MarketError::Unauthorized)));
+}
+
+// ============================================================================
+// 3️⃣ TESTS DE PAGINACIÓN
+// ============================================================================
+
+/// Test: las publicaciones se recorren por páginas
+#[test]
+fn test_listings_pagination() {
+    let env = Env::default();
+    let (buyer, provider) = (Address::generate(&env), Address::generate(&env));
+    let (_, market, _) = setup(&env, &buyer);
+
+    for capacity in 1..=5 {
+        review_listing(&env, &market, &provider, capacity);
+    }
+    assert_eq!(market.listing_count(), 5);
+
+    let page = market.listings(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().id, 1);
+
+    let page = market.listings(&4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().capacity, 5);
+
+    assert_eq!(market.listings(&10, &2).len(), 0);
+}
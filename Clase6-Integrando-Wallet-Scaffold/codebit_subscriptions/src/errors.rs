@@ -0,0 +1,33 @@
+// src/errors.rs
+use soroban_sdk::contracterror;
+
+/// Errores del contrato de suscripciones
+/// Los códigos empiezan en 1 (0 está reservado para "sin error")
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    /// El plan solicitado no existe
+    PlanNotFound = 1,
+
+    /// La suscripción no existe (nunca creada, cancelada o completada)
+    SubscriptionNotFound = 2,
+
+    /// Monto del plan debe ser > 0
+    InvalidAmount = 3,
+
+    /// Período o cantidad de períodos en 0
+    InvalidPeriod = 4,
+
+    /// Todavía no llegó el límite del siguiente período
+    PaymentNotDue = 5,
+
+    /// La cuenta no es parte de la suscripción
+    Unauthorized = 6,
+
+    /// Overflow en operación aritmética
+    OverflowError = 7,
+
+    /// transfer_from falló (allowance o balance insuficiente)
+    PaymentFailed = 8,
+}
@@ -0,0 +1,204 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+mod storage;
+mod errors;
+
+pub use storage::{Plan, Subscription};
+use storage::DataKey;
+use errors::SubscriptionError;
+
+/// Trait de suscripciones recurrentes pagadas en CODEBIT
+///
+/// El suscriptor aprueba (approve) a este contrato en el token y el
+/// proveedor "tira" cada pago con transfer_from al llegar cada período.
+pub trait SubscriptionTrait {
+    /// Un proveedor publica un plan (monto, período, máximo de períodos)
+    fn create_plan(
+        env: Env,
+        provider: Address,
+        token: Address,
+        amount: i128,
+        period: u64,
+        max_periods: u32
+    ) -> Result<u64, SubscriptionError>;
+
+    /// Un miembro se suscribe a un plan (el primer período vence ya)
+    fn subscribe(env: Env, subscriber: Address, plan_id: u64) -> Result<u64, SubscriptionError>;
+
+    /// El proveedor cobra todos los períodos vencidos
+    /// Devuelve cuántos períodos se cobraron
+    fn collect(env: Env, subscription_id: u64) -> Result<u32, SubscriptionError>;
+
+    /// Suscriptor o proveedor cancelan la suscripción
+    fn cancel(env: Env, caller: Address, subscription_id: u64) -> Result<(), SubscriptionError>;
+
+    // Métodos de consulta
+    fn get_plan(env: Env, plan_id: u64) -> Result<Plan, SubscriptionError>;
+    fn get_subscription(env: Env, subscription_id: u64) -> Result<Subscription, SubscriptionError>;
+    fn next_payment_at(env: Env, subscription_id: u64) -> Result<u64, SubscriptionError>;
+}
+
+#[contract]
+pub struct CodebitSubscriptions;
+
+#[contractimpl]
+impl SubscriptionTrait for CodebitSubscriptions {
+    fn create_plan(
+        env: Env,
+        provider: Address,
+        token: Address,
+        amount: i128,
+        period: u64,
+        max_periods: u32
+    ) -> Result<u64, SubscriptionError> {
+        // 1. Solo el proveedor publica sus planes
+        provider.require_auth();
+
+        // 2. Validaciones
+        if amount <= 0 {
+            return Err(SubscriptionError::InvalidAmount);
+        }
+
+        if period == 0 || max_periods == 0 {
+            return Err(SubscriptionError::InvalidPeriod);
+        }
+
+        // 3. Guardar el plan con id incremental
+        let plan_id: u64 = env.storage().instance()
+            .get(&DataKey::PlanCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::PlanCount, &(plan_id + 1));
+
+        let plan = Plan { provider, token, amount, period, max_periods };
+        env.storage().persistent().set(&DataKey::Plan(plan_id), &plan);
+        env.storage().persistent().extend_ttl(&DataKey::Plan(plan_id), 100_000, 200_000);
+
+        env.storage().instance().extend_ttl(100_000, 200_000);
+
+        Ok(plan_id)
+    }
+
+    fn subscribe(env: Env, subscriber: Address, plan_id: u64) -> Result<u64, SubscriptionError> {
+        // 1. El suscriptor autoriza el plan
+        subscriber.require_auth();
+
+        let plan = read_plan(&env, plan_id)?;
+        if plan.provider == subscriber {
+            return Err(SubscriptionError::Unauthorized);
+        }
+
+        // 2. Guardar la suscripción empezando ahora
+        let subscription_id: u64 = env.storage().instance()
+            .get(&DataKey::SubscriptionCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::SubscriptionCount, &(subscription_id + 1));
+
+        let subscription = Subscription {
+            plan_id,
+            subscriber,
+            start: env.ledger().timestamp(),
+            periods_paid: 0,
+        };
+        write_subscription(&env, subscription_id, &subscription);
+
+        Ok(subscription_id)
+    }
+
+    fn collect(env: Env, subscription_id: u64) -> Result<u32, SubscriptionError> {
+        let mut subscription = read_subscription(&env, subscription_id)?;
+        let plan = read_plan(&env, subscription.plan_id)?;
+
+        // 1. Solo el proveedor cobra
+        plan.provider.require_auth();
+
+        // 2. Períodos vencidos: el período N vence en start + N * period
+        let elapsed = env.ledger().timestamp() - subscription.start;
+        let due = (elapsed / plan.period + 1).min(plan.max_periods as u64) as u32;
+        if due <= subscription.periods_paid {
+            return Err(SubscriptionError::PaymentNotDue);
+        }
+
+        let periods = due - subscription.periods_paid;
+        let amount = plan.amount
+            .checked_mul(periods as i128)
+            .ok_or(SubscriptionError::OverflowError)?;
+
+        // 3. Pull payment: este contrato es el spender aprobado
+        let token = token::TokenClient::new(&env, &plan.token);
+        let payment = token.try_transfer_from(
+            &env.current_contract_address(),
+            &subscription.subscriber,
+            &plan.provider,
+            &amount
+        );
+        if !matches!(payment, Ok(Ok(()))) {
+            return Err(SubscriptionError::PaymentFailed);
+        }
+
+        // 4. Actualizar o cerrar si ya se cobraron todos los períodos
+        subscription.periods_paid = due;
+        if subscription.periods_paid == plan.max_periods {
+            env.storage().persistent().remove(&DataKey::Subscription(subscription_id));
+        } else {
+            write_subscription(&env, subscription_id, &subscription);
+        }
+
+        Ok(periods)
+    }
+
+    fn cancel(env: Env, caller: Address, subscription_id: u64) -> Result<(), SubscriptionError> {
+        let subscription = read_subscription(&env, subscription_id)?;
+        let plan = read_plan(&env, subscription.plan_id)?;
+
+        // Cualquiera de las dos partes puede cancelar
+        caller.require_auth();
+        if caller != subscription.subscriber && caller != plan.provider {
+            return Err(SubscriptionError::Unauthorized);
+        }
+
+        env.storage().persistent().remove(&DataKey::Subscription(subscription_id));
+
+        Ok(())
+    }
+
+    // Métodos de consulta
+    fn get_plan(env: Env, plan_id: u64) -> Result<Plan, SubscriptionError> {
+        read_plan(&env, plan_id)
+    }
+
+    fn get_subscription(env: Env, subscription_id: u64) -> Result<Subscription, SubscriptionError> {
+        read_subscription(&env, subscription_id)
+    }
+
+    fn next_payment_at(env: Env, subscription_id: u64) -> Result<u64, SubscriptionError> {
+        let subscription = read_subscription(&env, subscription_id)?;
+        let plan = read_plan(&env, subscription.plan_id)?;
+        plan.period
+            .checked_mul(subscription.periods_paid as u64)
+            .and_then(|offset| subscription.start.checked_add(offset))
+            .ok_or(SubscriptionError::OverflowError)
+    }
+}
+
+fn read_plan(env: &Env, plan_id: u64) -> Result<Plan, SubscriptionError> {
+    env.storage().persistent()
+        .get(&DataKey::Plan(plan_id))
+        .ok_or(SubscriptionError::PlanNotFound)
+}
+
+fn read_subscription(env: &Env, subscription_id: u64) -> Result<Subscription, SubscriptionError> {
+    env.storage().persistent()
+        .get(&DataKey::Subscription(subscription_id))
+        .ok_or(SubscriptionError::SubscriptionNotFound)
+}
+
+fn write_subscription(env: &Env, subscription_id: u64, subscription: &Subscription) {
+    let key = DataKey::Subscription(subscription_id);
+    env.storage().persistent().set(&key, subscription);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,49 @@
+// src/storage.rs
+use soroban_sdk::{contracttype, Address};
+
+/// Claves de almacenamiento del contrato de suscripciones
+///
+/// - Instance Storage: contadores globales
+/// - Persistent Storage: planes y suscripciones (requieren TTL)
+#[contracttype]
+pub enum DataKey {
+    /// Plan publicado por un proveedor - Persistent Storage
+    Plan(u64),
+
+    /// Contador de planes creados - Instance Storage
+    PlanCount,
+
+    /// Suscripción activa de un miembro - Persistent Storage
+    Subscription(u64),
+
+    /// Contador de suscripciones creadas - Instance Storage
+    SubscriptionCount,
+}
+
+/// Plan de suscripción (ej: grupo de estudio semanal)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Plan {
+    /// Quien cobra cada período
+    pub provider: Address,
+    /// Token SEP-41 en el que se cobra (CODEBIT)
+    pub token: Address,
+    /// Monto cobrado por período
+    pub amount: i128,
+    /// Duración del período en segundos de ledger
+    pub period: u64,
+    /// Cantidad máxima de períodos a cobrar
+    pub max_periods: u32,
+}
+
+/// Suscripción de un miembro a un plan
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub plan_id: u64,
+    pub subscriber: Address,
+    /// Timestamp en que empieza el primer período
+    pub start: u64,
+    /// Períodos ya cobrados por el proveedor
+    pub periods_paid: u32,
+}
@@ -0,0 +1,162 @@
+// src/test.rs
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use token_codebit::{TokenCodebit, TokenCodebitClient};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+/// Helper: registra CODEBIT + suscripciones y mintea al suscriptor
+fn setup<'a>(
+    env: &Env,
+    subscriber: &Address,
+    amount: i128
+) -> (TokenCodebitClient<'a>, CodebitSubscriptionsClient<'a>) {
+    env.mock_all_auths();
+
+    let token_id = env.register(TokenCodebit, ());
+    let token = TokenCodebitClient::new(env, &token_id);
+    let admin = Address::generate(env);
+    token.initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    );
+    token.mint(subscriber, &amount);
+
+    let contract_id = env.register(CodebitSubscriptions, ());
+    let client = CodebitSubscriptionsClient::new(env, &contract_id);
+
+    (token, client)
+}
+
+// ============================================================================
+// 1️⃣ TESTS DE COBRO POR PERÍODOS
+// ============================================================================
+
+/// Test: el proveedor cobra una vez por semana, nunca antes del límite
+#[test]
+fn test_collect_across_periods() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10_000);
+    let member = Address::generate(&env);
+    let study_group = Address::generate(&env);
+    let (token, client) = setup(&env, &member, 1000);
+
+    // 60 CODEBIT por semana durante 4 semanas
+    let plan_id = client.create_plan(&study_group, &token.address, &60, &WEEK, &4);
+    token.approve(&member, &client.address, &240);
+    let sub_id = client.subscribe(&member, &plan_id);
+
+    // Primer período vence al suscribirse
+    assert_eq!(client.collect(&sub_id), 1);
+    assert_eq!(token.balance(&study_group), 60);
+
+    // Mismo período: no se puede cobrar otra vez
+    env.ledger().set_timestamp(10_000 + WEEK - 1);
+    assert_eq!(client.try_collect(&sub_id), Err(Ok(SubscriptionError::PaymentNotDue)));
+    assert_eq!(client.next_payment_at(&sub_id), 10_000 + WEEK);
+
+    // Pasan dos semanas más: se cobran dos períodos juntos
+    env.ledger().set_timestamp(10_000 + 2 * WEEK);
+    assert_eq!(client.collect(&sub_id), 2);
+    assert_eq!(token.balance(&study_group), 180);
+    assert_eq!(client.get_subscription(&sub_id).periods_paid, 3);
+
+    // Mucho después solo queda un período (tope max_periods)
+    env.ledger().set_timestamp(10_000 + 10 * WEEK);
+    assert_eq!(client.collect(&sub_id), 1);
+    assert_eq!(token.balance(&member), 760);
+    assert_eq!(token.balance(&study_group), 240);
+
+    // Suscripción completada y eliminada
+    assert_eq!(
+        client.try_get_subscription(&sub_id),
+        Err(Ok(SubscriptionError::SubscriptionNotFound))
+    );
+}
+
+/// Test: sin allowance suficiente el cobro falla sin cambiar estado
+#[test]
+fn test_collect_without_allowance_fails() {
+    let env = Env::default();
+    let member = Address::generate(&env);
+    let study_group = Address::generate(&env);
+    let (token, client) = setup(&env, &member, 1000);
+
+    let plan_id = client.create_plan(&study_group, &token.address, &60, &WEEK, &4);
+    let sub_id = client.subscribe(&member, &plan_id);
+
+    assert_eq!(client.try_collect(&sub_id), Err(Ok(SubscriptionError::PaymentFailed)));
+    assert_eq!(client.get_subscription(&sub_id).periods_paid, 0);
+    assert_eq!(token.balance(&member), 1000);
+}
+
+// ============================================================================
+// 2️⃣ TESTS DE CANCELACIÓN Y VALIDACIONES
+// ============================================================================
+
+/// Test: cualquiera de las partes cancela, un tercero no
+#[test]
+fn test_cancel_by_either_side() {
+    let env = Env::default();
+    let member = Address::generate(&env);
+    let study_group = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let (token, client) = setup(&env, &member, 1000);
+
+    let plan_id = client.create_plan(&study_group, &token.address, &60, &WEEK, &4);
+    let first = client.subscribe(&member, &plan_id);
+    let second = client.subscribe(&member, &plan_id);
+
+    assert_eq!(client.try_cancel(&stranger, &first), Err(Ok(SubscriptionError::Unauthorized)));
+
+    client.cancel(&member, &first);
+    client.cancel(&study_group, &second);
+
+    assert_eq!(client.try_collect(&first), Err(Ok(SubscriptionError::SubscriptionNotFound)));
+    assert_eq!(client.try_collect(&second), Err(Ok(SubscriptionError::SubscriptionNotFound)));
+}
+
+/// Test: planes inválidos
+#[test]
+fn test_invalid_plans() {
+    let env = Env::default();
+    let member = Address::generate(&env);
+    let study_group = Address::generate(&env);
+    let (token, client) = setup(&env, &member, 1000);
+
+    let result = client.try_create_plan(&study_group, &token.address, &0, &WEEK, &4);
+    assert_eq!(result, Err(Ok(SubscriptionError::InvalidAmount)));
+
+    let result = client.try_create_plan(&study_group, &token.address, &60, &0, &4);
+    assert_eq!(result, Err(Ok(SubscriptionError::InvalidPeriod)));
+
+    let result = client.try_create_plan(&study_group, &token.address, &60, &WEEK, &0);
+    assert_eq!(result, Err(Ok(SubscriptionError::InvalidPeriod)));
+
+    assert_eq!(client.try_subscribe(&member, &7), Err(Ok(SubscriptionError::PlanNotFound)));
+}
+
+/// Test: un período enorme no hace fallar la consulta del próximo pago
+#[test]
+fn test_next_payment_overflow() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10_000);
+    let member = Address::generate(&env);
+    let study_group = Address::generate(&env);
+    let (token, client) = setup(&env, &member, 1000);
+
+    let plan_id = client.create_plan(&study_group, &token.address, &60, &u64::MAX, &2);
+    token.approve(&member, &client.address, &120);
+    let sub_id = client.subscribe(&member, &plan_id);
+    assert_eq!(client.next_payment_at(&sub_id), 10_000);
+
+    client.collect(&sub_id);
+    assert_eq!(client.try_next_payment_at(&sub_id), Err(Ok(SubscriptionError::OverflowError)));
+}
@@ -0,0 +1,25 @@
+// src/errors.rs
+use soroban_sdk::contracterror;
+
+/// Errores del swap atómico
+/// Los códigos empiezan en 1 (0 está reservado para "sin error")
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SwapError {
+    /// Montos ofrecidos deben ser > 0 y mínimos >= 0
+    InvalidAmount = 1,
+
+    /// Lo ofrecido por una parte no cubre el mínimo de la otra
+    PriceNotMet = 2,
+
+    /// Las dos partes son la misma address
+    SameParty = 3,
+
+    /// Los dos lados usan el mismo token
+    SameToken = 4,
+
+    /// El token no movió exactamente el monto pedido
+    /// Ej: tokens con comisión por transferencia
+    TransferMismatch = 5,
+}
@@ -0,0 +1,131 @@
+#![no_std]
+// swap() recibe las dos ofertas completas (8 parámetros + env)
+#![allow(clippy::too_many_arguments)]
+
+use soroban_sdk::{contract, contractevent, contractimpl, token, Address, Env, IntoVal};
+
+mod errors;
+
+pub use errors::SwapError;
+
+#[contractevent(topics = ["swap"])]
+pub struct Swapped {
+    #[topic]
+    pub a: Address,
+    #[topic]
+    pub b: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    /// Monto de token_a que recibió b
+    pub amount_a: i128,
+    /// Monto de token_b que recibió a
+    pub amount_b: i128,
+}
+
+/// Trait del swap atómico
+///
+/// Basado en el ejemplo clásico de Soroban, con estas diferencias:
+/// - errores tipados en lugar de panics
+/// - cada parte recibe exactamente el mínimo pedido por la otra y el
+///   resto nunca sale de su cuenta (sin custodia intermedia)
+/// - se verifican los balances después de cada transferencia
+pub trait SwapTrait {
+    /// Intercambia tokens entre `a` y `b`; ambas partes deben autorizar
+    ///
+    /// `a` ofrece hasta `amount_a` de `token_a` y pide al menos `min_b_for_a`
+    /// de `token_b`; `b` ofrece hasta `amount_b` de `token_b` y pide al menos
+    /// `min_a_for_b` de `token_a`.
+    fn swap(
+        env: Env,
+        a: Address,
+        b: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a: i128,
+        min_b_for_a: i128,
+        amount_b: i128,
+        min_a_for_b: i128
+    ) -> Result<(), SwapError>;
+}
+
+#[contract]
+pub struct AtomicSwap;
+
+#[contractimpl]
+impl SwapTrait for AtomicSwap {
+    fn swap(
+        env: Env,
+        a: Address,
+        b: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a: i128,
+        min_b_for_a: i128,
+        amount_b: i128,
+        min_a_for_b: i128
+    ) -> Result<(), SwapError> {
+        // 1. Validaciones
+        if amount_a <= 0 || amount_b <= 0 || min_b_for_a < 0 || min_a_for_b < 0 {
+            return Err(SwapError::InvalidAmount);
+        }
+
+        if a == b {
+            return Err(SwapError::SameParty);
+        }
+
+        if token_a == token_b {
+            return Err(SwapError::SameToken);
+        }
+
+        // 2. Las ofertas tienen que cruzarse
+        if amount_b < min_b_for_a || amount_a < min_a_for_b {
+            return Err(SwapError::PriceNotMet);
+        }
+
+        // 3. Cada parte firma solo su lado de la oferta
+        a.require_auth_for_args(
+            (token_a.clone(), token_b.clone(), amount_a, min_b_for_a).into_val(&env)
+        );
+        b.require_auth_for_args(
+            (token_b.clone(), token_a.clone(), amount_b, min_a_for_b).into_val(&env)
+        );
+
+        // 4. Ambas patas o ninguna: cualquier fallo revierte la transacción
+        move_token(&env, &token_a, &a, &b, min_a_for_b)?;
+        move_token(&env, &token_b, &b, &a, min_b_for_a)?;
+
+        Swapped {
+            a,
+            b,
+            token_a,
+            token_b,
+            amount_a: min_a_for_b,
+            amount_b: min_b_for_a,
+        }.publish(&env);
+
+        Ok(())
+    }
+}
+
+/// Transfiere `amount` de `from` a `to` verificando que el token
+/// movió exactamente ese monto
+fn move_token(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) -> Result<(), SwapError> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let client = token::TokenClient::new(env, token);
+    let from_before = client.balance(from);
+    let to_before = client.balance(to);
+
+    client.transfer(from, to, &amount);
+
+    if client.balance(from) != from_before - amount || client.balance(to) != to_before + amount {
+        return Err(SwapError::TransferMismatch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,139 @@
+// src/test.rs
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, IntoVal, String, Symbol,
+};
+use token_codebit::{TokenCodebit, TokenCodebitClient};
+
+/// Helper: CODEBIT para `a`, USDC (Stellar Asset Contract) para `b` y el swap
+fn setup<'a>(
+    env: &Env,
+    a: &Address,
+    b: &Address
+) -> (TokenCodebitClient<'a>, TokenClient<'a>, AtomicSwapClient<'a>) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let codebit = TokenCodebitClient::new(env, &env.register(TokenCodebit, ()));
+    codebit.initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    );
+    codebit.mint(a, &120);
+
+    let usdc_id = env.register_stellar_asset_contract_v2(admin).address();
+    StellarAssetClient::new(env, &usdc_id).mint(b, &5_000);
+
+    let swap = AtomicSwapClient::new(env, &env.register(AtomicSwap, ()));
+
+    (codebit, TokenClient::new(env, &usdc_id), swap)
+}
+
+// ============================================================================
+// 1️⃣ TESTS DE SWAP
+// ============================================================================
+
+/// Test: minutos de CODEBIT por USDC, cada parte recibe lo que pidió
+#[test]
+fn test_swap_codebit_for_usdc() {
+    let env = Env::default();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let (codebit, usdc, swap) = setup(&env, &a, &b);
+
+    // a ofrece 60 minutos y pide 1000 USDC; b ofrece 1200 y pide 50 minutos
+    swap.swap(&a, &b, &codebit.address, &usdc.address, &60, &1_000, &1_200, &50);
+
+    // Cada parte firmó solo su lado de la oferta
+    assert_eq!(
+        env.auths()[0],
+        (
+            a.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    swap.address.clone(),
+                    Symbol::new(&env, "swap"),
+                    (codebit.address.clone(), usdc.address.clone(), 60_i128, 1_000_i128).into_val(&env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        codebit.address.clone(),
+                        Symbol::new(&env, "transfer"),
+                        (a.clone(), b.clone(), 50_i128).into_val(&env),
+                    )),
+                    sub_invocations: std::vec![],
+                }],
+            }
+        )
+    );
+
+    assert_eq!(codebit.balance(&a), 70);
+    assert_eq!(codebit.balance(&b), 50);
+    assert_eq!(usdc.balance(&a), 1_000);
+    assert_eq!(usdc.balance(&b), 4_000);
+
+    // El contrato nunca queda con fondos
+    assert_eq!(codebit.balance(&swap.address), 0);
+    assert_eq!(usdc.balance(&swap.address), 0);
+}
+
+/// Test: si las ofertas no se cruzan no se mueve nada
+#[test]
+fn test_swap_price_not_met() {
+    let env = Env::default();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let (codebit, usdc, swap) = setup(&env, &a, &b);
+
+    // b ofrece menos USDC de lo que a pide
+    let result = swap.try_swap(&a, &b, &codebit.address, &usdc.address, &60, &1_000, &900, &50);
+    assert_eq!(result, Err(Ok(SwapError::PriceNotMet)));
+
+    // a ofrece menos minutos de lo que b pide
+    let result = swap.try_swap(&a, &b, &codebit.address, &usdc.address, &40, &1_000, &1_200, &50);
+    assert_eq!(result, Err(Ok(SwapError::PriceNotMet)));
+
+    assert_eq!(codebit.balance(&a), 120);
+    assert_eq!(usdc.balance(&b), 5_000);
+}
+
+/// Test: si una pata falla la otra se revierte
+#[test]
+fn test_swap_is_atomic() {
+    let env = Env::default();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let (codebit, usdc, swap) = setup(&env, &a, &b);
+
+    // b no tiene 6000 USDC: la pata de CODEBIT (ya ejecutada) se revierte
+    let result = swap.try_swap(&a, &b, &codebit.address, &usdc.address, &60, &6_000, &6_000, &50);
+    assert!(result.is_err());
+
+    assert_eq!(codebit.balance(&a), 120);
+    assert_eq!(codebit.balance(&b), 0);
+    assert_eq!(usdc.balance(&b), 5_000);
+}
+
+/// Test: parámetros inválidos se rechazan con errores tipados
+#[test]
+fn test_swap_invalid_params() {
+    let env = Env::default();
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let (codebit, usdc, swap) = setup(&env, &a, &b);
+
+    let result = swap.try_swap(&a, &b, &codebit.address, &usdc.address, &0, &0, &1_000, &0);
+    assert_eq!(result, Err(Ok(SwapError::InvalidAmount)));
+
+    let result = swap.try_swap(&a, &b, &codebit.address, &usdc.address, &60, &-1, &1_000, &50);
+    assert_eq!(result, Err(Ok(SwapError::InvalidAmount)));
+
+    let result = swap.try_swap(&a, &a, &codebit.address, &usdc.address, &60, &1_000, &1_200, &50);
+    assert_eq!(result, Err(Ok(SwapError::SameParty)));
+
+    let result = swap.try_swap(&a, &b, &codebit.address, &codebit.address, &60, &50, &60, &50);
+    assert_eq!(result, Err(Ok(SwapError::SameToken)));
+}
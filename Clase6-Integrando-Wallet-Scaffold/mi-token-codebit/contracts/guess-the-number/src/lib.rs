@@ -1,51 +1,623 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Bytes,
+    BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
 
 #[contract]
 pub struct GuessTheNumber;
 
-const THE_NUMBER: Symbol = symbol_short!("n");
+const GAME_COUNT_KEY: Symbol = symbol_short!("GAMES");
+const OPEN_GAMES_KEY: Symbol = symbol_short!("OPEN");
+const LEADERBOARD_KEY: Symbol = symbol_short!("LEADERS");
 pub const ADMIN_KEY: &Symbol = &symbol_short!("ADMIN");
 
+/// Max guesses per game, so `reveal` and refunds fit in one transaction
+pub const MAX_GUESSES: u32 = 100;
+
+/// Max games waiting to be settled at the same time
+pub const MAX_OPEN_GAMES: u32 = 50;
+
+/// Players kept in the leaderboard
+pub const LEADERBOARD_SIZE: u32 = 10;
+
+/// Persistent entries are bumped on every write so a game can always be
+/// settled or refunded
+const TTL_THRESHOLD: u32 = 100_000;
+const TTL_EXTEND_TO: u32 = 200_000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// The constructor already set an admin
+    AdminAlreadySet = 1,
+    /// No admin was ever set
+    AdminNotSet = 2,
+    /// No game with that id
+    NoActiveRound = 3,
+    /// The game was settled or is past its deadline
+    RoundClosed = 4,
+    /// Guess outside the game's range
+    NumberOutOfRange = 5,
+    /// The player used all their attempts in this game
+    TooManyAttempts = 6,
+    /// The game reached `MAX_GUESSES`
+    RoundFull = 7,
+    /// Negative pot or entry fee
+    InvalidAmount = 8,
+    /// Empty range, no attempts or deadlines not in the future
+    InvalidConfig = 9,
+    /// `MAX_OPEN_GAMES` games are waiting to be settled
+    TooManyOpenGames = 10,
+    /// Guesses are still accepted, so the number can't be revealed yet
+    GuessingOpen = 11,
+    /// The admin can still reveal, so there is nothing to refund yet
+    RevealOpen = 12,
+    /// The number and salt don't hash to the game's commitment
+    CommitmentMismatch = 13,
+    /// The player has no winnings or refunds waiting in that game
+    NothingToClaim = 14,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Game by id
+    Game(u32),
+    /// Guesses recorded for a game
+    Guesses(u32),
+    /// Guesses a player made in a game
+    Attempts(u32, Address),
+    /// Lifetime stats of a player
+    Stats(Address),
+    /// Unclaimed prizes carried into the next game with this token
+    Rollover(Address),
+    /// Winnings and refunds a player can collect from a game
+    Claimable(u32, Address),
+}
+
+/// Rules chosen by the admin when creating a game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    /// Smallest valid guess
+    pub min: u64,
+    /// Largest valid guess
+    pub max: u64,
+    /// Guesses allowed per player
+    pub max_attempts: u32,
+    /// Last ledger that accepts guesses
+    pub deadline: u32,
+    /// Last ledger the admin can reveal; after it players get refunds
+    pub reveal_deadline: u32,
+}
+
+/// A game whose secret number is committed up front
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub config: GameConfig,
+    /// Any SEP-41 token (XLM SAC, CODEBIT, ...)
+    pub token: Address,
+    /// Funded by the admin and the rollover, plus every entry fee paid in this game
+    pub pot: i128,
+    /// Charged to the guesser on every guess (0 = free)
+    pub entry_fee: i128,
+    /// `sha256(number as 8 big-endian bytes || salt)`
+    pub commitment: BytesN<32>,
+    /// Guesses made by all players
+    pub attempts: u32,
+    /// Revealed or refunded; no more guesses or reveals
+    pub settled: bool,
+    /// Ledger the game was created; the next round keeps the same windows
+    pub opened: u32,
+    /// Round started automatically when this one was revealed
+    pub next: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Guess {
+    pub guesser: Address,
+    pub number: u64,
+    /// Entry fee paid, returned if the admin never reveals
+    pub fee: i128,
+}
+
+/// Lifetime stats of a player across all games
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    /// Guesses made
+    pub attempts: u32,
+    /// Revealed games where they made the first correct guess
+    pub wins: u32,
+    /// Games won in a row, reset by a revealed game they didn't win
+    pub streak: u32,
+    /// Longest streak ever
+    pub best_streak: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub wins: u32,
+}
+
 #[contractimpl]
 impl GuessTheNumber {
-    pub fn __constructor(env: &Env, admin: Address) {
-        Self::set_admin(env, &admin);
+    pub fn __constructor(env: &Env, admin: Address) -> Result<(), Error> {
+        Self::set_admin(env, &admin)
+    }
+
+    /// Create a game committing to a secret number in `config.min..=config.max`.
+    ///
+    /// The pot is funded with `amount` of `token` from the admin plus any
+    /// prizes rolled over from earlier games with the same token. Guesses are
+    /// accepted until `config.deadline`, then the admin has until
+    /// `config.reveal_deadline` to `reveal`. Returns the new game id.
+    /// Only callable by admin.
+    pub fn create_game(
+        env: &Env,
+        token: Address,
+        amount: i128,
+        entry_fee: i128,
+        commitment: BytesN<32>,
+        config: GameConfig,
+    ) -> Result<u32, Error> {
+        let admin = Self::require_admin(env)?;
+        if amount < 0 || entry_fee < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if config.min >= config.max
+            || config.max_attempts == 0
+            || config.deadline <= env.ledger().sequence()
+            || config.reveal_deadline <= config.deadline
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        if Self::open_ids(env).len() >= MAX_OPEN_GAMES {
+            return Err(Error::TooManyOpenGames);
+        }
+
+        if amount > 0 {
+            token::TokenClient::new(env, &token).transfer(
+                &admin,
+                &env.current_contract_address(),
+                &amount,
+            );
+        }
+
+        Ok(Self::open_game(
+            env, token, amount, entry_fee, commitment, config,
+        ))
+    }
+
+    /// Guess a number within the game's range, paying the entry fee.
+    /// The guess is settled when the admin reveals the secret.
+    pub fn guess(env: &Env, game_id: u32, guesser: Address, a_number: u64) -> Result<(), Error> {
+        guesser.require_auth();
+
+        let mut game = Self::game(env, game_id)?;
+        if game.settled || env.ledger().sequence() > game.config.deadline {
+            return Err(Error::RoundClosed);
+        }
+        if a_number < game.config.min || a_number > game.config.max {
+            return Err(Error::NumberOutOfRange);
+        }
+        if game.attempts >= MAX_GUESSES {
+            return Err(Error::RoundFull);
+        }
+
+        let attempts_key = DataKey::Attempts(game_id, guesser.clone());
+        let attempts: u32 = env.storage().persistent().get(&attempts_key).unwrap_or(0);
+        if attempts >= game.config.max_attempts {
+            return Err(Error::TooManyAttempts);
+        }
+
+        if game.entry_fee > 0 {
+            token::TokenClient::new(env, &game.token).transfer(
+                &guesser,
+                &env.current_contract_address(),
+                &game.entry_fee,
+            );
+            game.pot += game.entry_fee;
+        }
+
+        let mut stats = Self::stats(env, guesser.clone());
+        stats.attempts += 1;
+        Self::write(env, &DataKey::Stats(guesser.clone()), &stats);
+
+        env.events()
+            .publish((symbol_short!("guess"), game_id, guesser.clone()), a_number);
+
+        let mut guesses = Self::guesses(env, game_id);
+        guesses.push_back(Guess {
+            guesser,
+            number: a_number,
+            fee: game.entry_fee,
+        });
+        game.attempts += 1;
+
+        Self::write(env, &DataKey::Guesses(game_id), &guesses);
+        Self::write(env, &attempts_key, &(attempts + 1));
+        Self::write(env, &DataKey::Game(game_id), &game);
+
+        Ok(())
+    }
+
+    /// Reveal a game's committed number once guessing is closed.
+    ///
+    /// The whole pot is credited to the first correct guess, who collects it
+    /// with `claim`; if nobody got it, it rolls into the next game with the
+    /// same token. With `next_commitment` a new round starts right away with
+    /// the same rules and guessing windows, funded by the rollover. Returns
+    /// the winner. Only callable by admin.
+    ///
+    /// Trust assumption: the admin knows every committed number, and the
+    /// contract can't tell their other accounts apart from real players, so
+    /// nothing stops them from winning a game (rolled-over pot included) by
+    /// guessing from a second account. Commit-reveal only protects players
+    /// from everyone else; play on tables whose admin you trust.
+    pub fn reveal(
+        env: &Env,
+        game_id: u32,
+        number: u64,
+        salt: BytesN<32>,
+        next_commitment: Option<BytesN<32>>,
+    ) -> Result<Option<Address>, Error> {
+        Self::require_admin(env)?;
+
+        let mut game = Self::game(env, game_id)?;
+        let now = env.ledger().sequence();
+        if game.settled || now > game.config.reveal_deadline {
+            return Err(Error::RoundClosed);
+        }
+        if now <= game.config.deadline {
+            return Err(Error::GuessingOpen);
+        }
+        if Self::commitment(env, number, &salt) != game.commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        let mut winner = None;
+        let mut players: Vec<Address> = Vec::new(env);
+        for guess in Self::guesses(env, game_id).iter() {
+            if winner.is_none() && guess.number == number {
+                winner = Some(guess.guesser.clone());
+            }
+            if !players.contains(&guess.guesser) {
+                players.push_back(guess.guesser);
+            }
+        }
+
+        match &winner {
+            Some(winner) => {
+                Self::credit(env, game_id, winner, game.pot);
+                env.events()
+                    .publish((symbol_short!("win"), game_id, winner.clone()), game.pot);
+            }
+            None => Self::roll_over(env, &game.token, game.pot),
+        }
+        game.pot = 0;
+
+        for player in players.iter() {
+            let won = winner.as_ref() == Some(&player);
+            Self::record_result(env, &player, won);
+        }
+
+        Self::settle(env, &mut game);
+
+        if let Some(commitment) = next_commitment {
+            let config = GameConfig {
+                deadline: now + (game.config.deadline - game.opened),
+                reveal_deadline: now + (game.config.reveal_deadline - game.opened),
+                ..game.config.clone()
+            };
+            let next = Self::open_game(
+                env,
+                game.token.clone(),
+                0,
+                game.entry_fee,
+                commitment,
+                config,
+            );
+            game.next = Some(next);
+            Self::write(env, &DataKey::Game(game_id), &game);
+        }
+
+        Ok(winner)
+    }
+
+    /// Refund a game the admin never revealed: entry fees are credited back to
+    /// the players and the rest of the pot is forfeited to them, split evenly,
+    /// so withholding the reveal costs the admin their funding. Any remainder
+    /// (or the whole pot if nobody played) rolls into the next game. Players
+    /// collect with `claim`. Anyone can call it after the reveal deadline;
+    /// returns the guesses refunded.
+    pub fn refund(env: &Env, game_id: u32) -> Result<u32, Error> {
+        let mut game = Self::game(env, game_id)?;
+        if game.settled {
+            return Err(Error::RoundClosed);
+        }
+        if env.ledger().sequence() <= game.config.reveal_deadline {
+            return Err(Error::RevealOpen);
+        }
+
+        let guesses = Self::guesses(env, game_id);
+        let mut players: Vec<Address> = Vec::new(env);
+        for guess in guesses.iter() {
+            Self::credit(env, game_id, &guess.guesser, guess.fee);
+            game.pot -= guess.fee;
+            if !players.contains(&guess.guesser) {
+                players.push_back(guess.guesser);
+            }
+        }
+
+        if !players.is_empty() {
+            let share = game.pot / players.len() as i128;
+            for player in players.iter() {
+                Self::credit(env, game_id, &player, share);
+            }
+            game.pot -= share * players.len() as i128;
+        }
+        Self::roll_over(env, &game.token, game.pot);
+        game.pot = 0;
+
+        Self::settle(env, &mut game);
+
+        Ok(guesses.len())
+    }
+
+    /// Pay out what `reveal` or `refund` credited to `player` in a game.
+    /// Each player collects on their own, so one account that can't receive
+    /// the token doesn't block the others. Returns the amount paid.
+    pub fn claim(env: &Env, game_id: u32, player: Address) -> Result<i128, Error> {
+        player.require_auth();
+
+        let game = Self::game(env, game_id)?;
+        let key = DataKey::Claimable(game_id, player.clone());
+        let amount = Self::claimable(env, game_id, player.clone());
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        env.storage().persistent().remove(&key);
+
+        token::TokenClient::new(env, &game.token).transfer(
+            &env.current_contract_address(),
+            &player,
+            &amount,
+        );
+
+        Ok(amount)
+    }
+
+    /// A game by id
+    pub fn get_game(env: &Env, game_id: u32) -> Option<Game> {
+        env.storage().persistent().get(&DataKey::Game(game_id))
     }
 
-    /// Update the number. Only callable by admin.
-    pub fn reset(env: &Env) {
-        Self::require_admin(env);
-        let new_number: u64 = env.prng().gen_range(1..=10);
-        env.storage().instance().set(&THE_NUMBER, &new_number);
+    /// Games still accepting guesses
+    pub fn open_games(env: &Env) -> Vec<Game> {
+        let now = env.ledger().sequence();
+        let mut games = Vec::new(env);
+        for id in Self::open_ids(env).iter() {
+            if let Some(game) = Self::get_game(env, id) {
+                if now <= game.config.deadline {
+                    games.push_back(game);
+                }
+            }
+        }
+        games
     }
 
-    /// Guess a number between 1 and 10
-    pub fn guess(env: &Env, a_number: u64) -> bool {
-        a_number == env.storage().instance().get::<_, u64>(&THE_NUMBER).unwrap()
+    /// Guesses recorded for a game
+    pub fn guesses(env: &Env, game_id: u32) -> Vec<Guess> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Guesses(game_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Lifetime stats of a player
+    pub fn stats(env: &Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stats(player))
+            .unwrap_or_default()
+    }
+
+    /// Players with the most wins, best first
+    pub fn leaderboard(env: &Env) -> Vec<LeaderboardEntry> {
+        env.storage()
+            .instance()
+            .get(&LEADERBOARD_KEY)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Prizes waiting to be added to the next game with `token`
+    pub fn rollover(env: &Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rollover(token))
+            .unwrap_or(0)
+    }
+
+    /// Winnings and refunds waiting for `player` in a game
+    pub fn claimable(env: &Env, game_id: u32, player: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(game_id, player))
+            .unwrap_or(0)
+    }
+
+    /// Guesses a player made in a game
+    pub fn attempts(env: &Env, game_id: u32, player: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attempts(game_id, player))
+            .unwrap_or(0)
     }
 
     /// Upgrade the contract to new wasm. Only callable by admin.
-    pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
-        Self::require_admin(env);
+    pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(env)?;
         env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    fn game(env: &Env, game_id: u32) -> Result<Game, Error> {
+        Self::get_game(env, game_id).ok_or(Error::NoActiveRound)
+    }
+
+    fn open_ids(env: &Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&OPEN_GAMES_KEY)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store a new game funded with `amount` plus the rollover for its token
+    fn open_game(
+        env: &Env,
+        token: Address,
+        amount: i128,
+        entry_fee: i128,
+        commitment: BytesN<32>,
+        config: GameConfig,
+    ) -> u32 {
+        let rollover = Self::rollover(env, token.clone());
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Rollover(token.clone()));
+
+        let id: u32 = env.storage().instance().get(&GAME_COUNT_KEY).unwrap_or(0);
+        let game = Game {
+            id,
+            config,
+            token,
+            pot: amount + rollover,
+            entry_fee,
+            commitment,
+            attempts: 0,
+            settled: false,
+            opened: env.ledger().sequence(),
+            next: None,
+        };
+        Self::write(env, &DataKey::Game(id), &game);
+        env.storage().instance().set(&GAME_COUNT_KEY, &(id + 1));
+
+        let mut open = Self::open_ids(env);
+        open.push_back(id);
+        env.storage().instance().set(&OPEN_GAMES_KEY, &open);
+
+        id
+    }
+
+    fn settle(env: &Env, game: &mut Game) {
+        game.settled = true;
+        Self::write(env, &DataKey::Game(game.id), game);
+
+        let mut open = Self::open_ids(env);
+        if let Some(index) = open.first_index_of(game.id) {
+            open.remove(index);
+        }
+        env.storage().instance().set(&OPEN_GAMES_KEY, &open);
+    }
+
+    fn record_result(env: &Env, player: &Address, won: bool) {
+        let mut stats = Self::stats(env, player.clone());
+        if won {
+            stats.wins += 1;
+            stats.streak += 1;
+            stats.best_streak = stats.best_streak.max(stats.streak);
+        } else {
+            stats.streak = 0;
+        }
+        Self::write(env, &DataKey::Stats(player.clone()), &stats);
+
+        if won {
+            Self::update_leaderboard(env, player, stats.wins);
+        }
+    }
+
+    /// Wins only go up, so moving the player up to their new rank keeps the
+    /// board sorted; ties keep whoever got there first ahead
+    fn update_leaderboard(env: &Env, player: &Address, wins: u32) {
+        let mut board = Self::leaderboard(env);
+        if let Some(index) = board.iter().position(|entry| entry.player == *player) {
+            board.remove(index as u32);
+        }
+
+        let rank = board
+            .iter()
+            .position(|entry| entry.wins < wins)
+            .map_or(board.len(), |index| index as u32);
+        if rank >= LEADERBOARD_SIZE {
+            return;
+        }
+
+        board.insert(
+            rank,
+            LeaderboardEntry {
+                player: player.clone(),
+                wins,
+            },
+        );
+        if board.len() > LEADERBOARD_SIZE {
+            board.pop_back();
+        }
+        env.storage().instance().set(&LEADERBOARD_KEY, &board);
+    }
+
+    fn commitment(env: &Env, number: u64, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(env, &number.to_be_bytes());
+        preimage.append(&Bytes::from(salt));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    fn roll_over(env: &Env, token: &Address, amount: i128) {
+        if amount > 0 {
+            let total = Self::rollover(env, token.clone()) + amount;
+            Self::write(env, &DataKey::Rollover(token.clone()), &total);
+        }
+    }
+
+    fn credit(env: &Env, game_id: u32, player: &Address, amount: i128) {
+        if amount > 0 {
+            let total = Self::claimable(env, game_id, player.clone()) + amount;
+            Self::write(env, &DataKey::Claimable(game_id, player.clone()), &total);
+        }
+    }
+
+    fn write<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
     }
 
     fn admin(env: &Env) -> Option<Address> {
         env.storage().instance().get(ADMIN_KEY)
     }
 
-    fn set_admin(env: &Env, admin: &Address) {
+    fn set_admin(env: &Env, admin: &Address) -> Result<(), Error> {
         // Check if admin is already set
         if env.storage().instance().has(ADMIN_KEY) {
-            panic!("admin already set");
+            return Err(Error::AdminAlreadySet);
         }
         env.storage().instance().set(ADMIN_KEY, admin);
+        Ok(())
     }
 
-    fn require_admin(env: &Env) {
-        let admin = Self::admin(env).expect("admin not set");
+    fn require_admin(env: &Env) -> Result<Address, Error> {
+        let admin = Self::admin(env).ok_or(Error::AdminNotSet)?;
         admin.require_auth();
+        Ok(admin)
     }
 }
 
@@ -3,40 +3,726 @@ extern crate std;
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, MockAuth, MockAuthInvoke},
-    Address, Env, IntoVal, Val, Vec,
+    symbol_short,
+    testutils::{
+        storage::Persistent as _, Address as _, Events, IssuerFlags, Ledger, MockAuth,
+        MockAuthInvoke,
+    },
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Bytes, BytesN, Env, IntoVal, Val, Vec,
 };
 
+const GUESS_LEDGERS: u32 = 10;
+const REVEAL_LEDGERS: u32 = 20;
+
 #[test]
-fn only_admin_can_reset() {
+fn only_admin_can_create_game() {
     let env = &Env::default();
 
     let admin = Address::generate(env);
     let user = Address::generate(env);
 
     let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+    let commitment = commit(env, 7, &salt(env));
+    let config = config(env, 1, 10, 1);
+    let args = (&token, 0i128, 0i128, &commitment, config.clone());
+
+    set_caller(&client, "create_game", &user, args.clone());
+    assert!(client
+        .try_create_game(&token, &0, &0, &commitment, &config)
+        .is_err());
+
+    set_caller(&client, "create_game", &admin, args);
+    assert!(client
+        .try_create_game(&token, &0, &0, &commitment, &config)
+        .is_ok());
+}
+
+#[test]
+fn one_guess_in_ten_wins() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    // commit to the number
+    let (game_id, salt) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 4);
+
+    let players: std::vec::Vec<_> = (1u64..=10)
+        .map(|number| {
+            let player = Address::generate(env);
+            client.guess(&game_id, &player, &number);
+            player
+        })
+        .collect();
+    assert_eq!(client.guesses(&game_id).len(), 10);
+    assert_eq!(client.get_game(&game_id).unwrap().attempts, 10);
+
+    close_guessing(env);
+    let winner = client.reveal(&game_id, &4, &salt, &None);
+    assert_eq!(winner, Some(players[3].clone()));
+    assert!(client.get_game(&game_id).unwrap().settled);
+}
+
+#[test]
+fn first_correct_guess_takes_pot_and_entry_fees() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let loser = Address::generate(env);
+    let winner_a = Address::generate(env);
+    // also right, but after winner_a
+    let winner_b = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &1_000);
+    for player in [&loser, &winner_a, &winner_b] {
+        token_admin.mint(player, &100);
+    }
+
+    let (game_id, salt) = start_game(&client, &token.address, 500, 10, config(env, 1, 10, 1), 3);
+    assert_eq!(token.balance(&admin), 500);
+    assert_eq!(token.balance(&client.address), 500);
+
+    client.guess(&game_id, &loser, &5);
+    client.guess(&game_id, &winner_a, &3);
+    client.guess(&game_id, &winner_b, &3);
+    assert_eq!(token.balance(&loser), 90);
+    assert_eq!(client.get_game(&game_id).unwrap().pot, 530);
+
+    close_guessing(env);
+    let winner = client.reveal(&game_id, &3, &salt, &None);
+    assert_eq!(winner, Some(winner_a.clone()));
+    assert_eq!(client.claimable(&game_id, &winner_a), 530);
+    assert_eq!(client.claim(&game_id, &winner_a), 530);
+    assert_eq!(token.balance(&winner_a), 90 + 530);
+    assert_eq!(token.balance(&winner_b), 90);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_game(&game_id).unwrap().pot, 0);
+}
+
+#[test]
+fn unclaimed_pot_rolls_into_next_game() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &1_000);
+    token_admin.mint(&player, &100);
+
+    let (game_id, salt) = start_game(&client, &token.address, 500, 10, config(env, 1, 10, 1), 3);
+    client.guess(&game_id, &player, &5);
+
+    close_guessing(env);
+    assert!(client.reveal(&game_id, &3, &salt, &None).is_none());
+    assert_eq!(token.balance(&admin), 500);
+    assert_eq!(token.balance(&client.address), 510);
+    assert_eq!(client.rollover(&token.address), 510);
+
+    // the next game with the same token starts with the unclaimed pot
+    let (next, _) = start_game(&client, &token.address, 100, 10, config(env, 1, 10, 1), 3);
+    assert_eq!(client.get_game(&next).unwrap().pot, 610);
+    assert_eq!(client.rollover(&token.address), 0);
+}
+
+#[test]
+fn reveal_starts_next_round() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &1_000);
+    token_admin.mint(&player, &100);
+
+    let (game_id, salt) = start_game(&client, &token.address, 500, 10, config(env, 1, 10, 2), 3);
+    client.guess(&game_id, &player, &5);
+
+    close_guessing(env);
+    let next_commitment = commit(env, 8, &salt);
+    assert!(client
+        .reveal(&game_id, &3, &salt, &Some(next_commitment.clone()))
+        .is_none());
+
+    // same rules and windows from now on, funded by the unclaimed pot
+    let next = client.get_game(&game_id).unwrap().next.unwrap();
+    assert_eq!(client.open_games().len(), 1);
+    let game = client.get_game(&next).unwrap();
+    let now = env.ledger().sequence();
+    assert_eq!(game.pot, 510);
+    assert_eq!(game.entry_fee, 10);
+    assert_eq!(game.commitment, next_commitment);
+    assert_eq!((game.config.min, game.config.max), (1, 10));
+    assert_eq!(game.config.max_attempts, 2);
+    assert_eq!(game.config.deadline, now + GUESS_LEDGERS);
+    assert_eq!(
+        game.config.reveal_deadline,
+        now + GUESS_LEDGERS + REVEAL_LEDGERS
+    );
+    assert_eq!(client.rollover(&token.address), 0);
+
+    client.guess(&next, &player, &8);
+    close_guessing(env);
+    assert_eq!(client.reveal(&next, &8, &salt, &None), Some(player.clone()));
+    client.claim(&next, &player);
+    assert_eq!(token.balance(&player), 90 - 10 + 520);
+    assert_eq!(client.get_game(&next).unwrap().next, None);
+}
+
+#[test]
+fn reveal_must_match_commitment_after_guessing() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    let (game_id, salt) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 6);
+    client.guess(&game_id, &player, &6);
+
+    // revealing early would leak the number while guesses are open
+    assert_eq!(
+        client.try_reveal(&game_id, &6, &salt, &None),
+        Err(Ok(Error::GuessingOpen))
+    );
+
+    close_guessing(env);
+    let other = Address::generate(env);
+    assert_eq!(
+        client.try_guess(&game_id, &other, &6),
+        Err(Ok(Error::RoundClosed))
+    );
+    assert_eq!(
+        client.try_reveal(&game_id, &5, &salt, &None),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    assert_eq!(
+        client.try_reveal(&game_id, &6, &BytesN::from_array(env, &[9; 32]), &None),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+
+    assert_eq!(client.reveal(&game_id, &6, &salt, &None), Some(player));
+    assert_eq!(
+        client.try_reveal(&game_id, &6, &salt, &None),
+        Err(Ok(Error::RoundClosed))
+    );
+}
+
+#[test]
+fn unrevealed_game_refunds_players() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &1_000);
+    token_admin.mint(&player, &100);
+
+    let (game_id, salt) = start_game(&client, &token.address, 500, 10, config(env, 1, 10, 2), 2);
+    client.guess(&game_id, &player, &2);
+    client.guess(&game_id, &player, &7);
+    assert_eq!(token.balance(&player), 80);
+
+    close_guessing(env);
+    assert_eq!(client.try_refund(&game_id), Err(Ok(Error::RevealOpen)));
+
+    env.ledger()
+        .with_mut(|li| li.sequence_number += REVEAL_LEDGERS);
+    assert_eq!(client.refund(&game_id), 2);
+    assert_eq!(client.claim(&game_id, &player), 20 + 500);
+    assert_eq!(token.balance(&player), 100 + 500);
+    assert_eq!(token.balance(&admin), 500);
+    assert_eq!(
+        client.try_reveal(&game_id, &2, &salt, &None),
+        Err(Ok(Error::RoundClosed))
+    );
+    assert_eq!(client.try_refund(&game_id), Err(Ok(Error::RoundClosed)));
+}
+
+#[test]
+fn withheld_reveal_forfeits_admin_funding_to_players() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &1_000);
+    token_admin.mint(&alice, &100);
+    token_admin.mint(&bob, &100);
+
+    let (game_id, _) = start_game(&client, &token.address, 501, 10, config(env, 1, 10, 2), 4);
+    client.guess(&game_id, &alice, &4);
+    client.guess(&game_id, &alice, &5);
+    client.guess(&game_id, &bob, &6);
+
+    // the admin sees alice got it and never reveals
+    close_guessing(env);
+    env.ledger()
+        .with_mut(|li| li.sequence_number += REVEAL_LEDGERS);
+    assert_eq!(client.refund(&game_id), 3);
+    client.claim(&game_id, &alice);
+    client.claim(&game_id, &bob);
+
+    // fees back plus half of the funding each; the odd unit rolls over
+    assert_eq!(token.balance(&alice), 100 + 250);
+    assert_eq!(token.balance(&bob), 100 + 250);
+    assert_eq!(token.balance(&admin), 499);
+    assert_eq!(client.rollover(&token.address), 1);
+    assert_eq!(token.balance(&client.address), 1);
+}
+
+#[test]
+fn players_claim_even_if_another_cannot_receive() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    // a revocable asset, so the issuer can freeze an account's balance
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = TokenClient::new(env, &sac.address());
+    let token_admin = StellarAssetClient::new(env, &sac.address());
+    token_admin.mint(&admin, &1_000);
+    token_admin.mint(&alice, &100);
+    token_admin.mint(&bob, &100);
+
+    let (won, won_salt) = start_game(&client, &token.address, 100, 10, config(env, 1, 10, 1), 4);
+    let (withheld, _) = start_game(&client, &token.address, 200, 10, config(env, 1, 10, 1), 4);
+    client.guess(&won, &bob, &4);
+    client.guess(&won, &alice, &5);
+    client.guess(&withheld, &alice, &4);
+    client.guess(&withheld, &bob, &6);
+
+    // bob's account stops accepting the token after guessing
+    token_admin.set_authorized(&bob, &false);
+
+    // settling only records credits, so bob can't block it
+    close_guessing(env);
+    assert_eq!(client.reveal(&won, &4, &won_salt, &None), Some(bob.clone()));
+    env.ledger()
+        .with_mut(|li| li.sequence_number += REVEAL_LEDGERS);
+    assert_eq!(client.refund(&withheld), 2);
+
+    assert_eq!(client.claim(&withheld, &alice), 10 + 100);
+    assert_eq!(token.balance(&alice), 100 - 20 + 110);
+    assert!(client.try_claim(&withheld, &bob).is_err());
+    assert!(client.try_claim(&won, &bob).is_err());
+    assert_eq!(
+        client.try_claim(&won, &alice),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // the credits wait until bob can receive again
+    token_admin.set_authorized(&bob, &true);
+    assert_eq!(client.claim(&won, &bob), 120);
+    assert_eq!(client.claim(&withheld, &bob), 10 + 100);
+    assert_eq!(token.balance(&bob), 100 - 20 + 120 + 110);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn claim_requires_player_auth() {
+    let env = &Env::default();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+    let other = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+
+    env.mock_all_auths();
+    token_admin.mint(&admin, &100);
+    let (game_id, salt) = start_game(&client, &token.address, 100, 0, config(env, 1, 10, 1), 3);
+    client.guess(&game_id, &player, &3);
+    close_guessing(env);
+    client.reveal(&game_id, &3, &salt, &None);
+
+    set_caller(&client, "claim", &other, (game_id, &player));
+    assert!(client.try_claim(&game_id, &player).is_err());
+
+    set_caller(&client, "claim", &player, (game_id, &player));
+    assert_eq!(client.try_claim(&game_id, &player), Ok(Ok(100)));
+}
+
+#[test]
+fn games_run_concurrently_with_their_own_rules() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    let (small, small_salt) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 2);
+    let (big, big_salt) = start_game(&client, &token, 0, 0, config(env, 50, 100, 3), 77);
+    assert_eq!((small, big), (0, 1));
+    assert_eq!(client.open_games().len(), 2);
+
+    // range is per game
+    assert_eq!(
+        client.try_guess(&small, &player, &11),
+        Err(Ok(Error::NumberOutOfRange))
+    );
+    assert_eq!(
+        client.try_guess(&big, &player, &11),
+        Err(Ok(Error::NumberOutOfRange))
+    );
+    client.guess(&big, &player, &77);
+
+    // so are the attempts per player
+    client.guess(&small, &player, &2);
+    assert_eq!(
+        client.try_guess(&small, &player, &3),
+        Err(Ok(Error::TooManyAttempts))
+    );
+    client.guess(&big, &player, &60);
+    client.guess(&big, &player, &61);
+    assert_eq!(
+        client.try_guess(&big, &player, &62),
+        Err(Ok(Error::TooManyAttempts))
+    );
+    assert_eq!(client.attempts(&small, &player), 1);
+    assert_eq!(client.attempts(&big, &player), 3);
+
+    close_guessing(env);
+    assert!(client.open_games().is_empty());
+    assert_eq!(
+        client.reveal(&big, &77, &big_salt, &None),
+        Some(player.clone())
+    );
+    assert_eq!(client.reveal(&small, &2, &small_salt, &None), Some(player));
+}
+
+#[test]
+fn open_games_lists_games_accepting_guesses() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    let (first, _) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 1);
+    let mut long = config(env, 1, 10, 1);
+    long.deadline += 100;
+    long.reveal_deadline += 100;
+    let (second, _) = start_game(&client, &token, 0, 0, long, 1);
+
+    let open: std::vec::Vec<_> = client.open_games().iter().map(|game| game.id).collect();
+    assert_eq!(open, [first, second]);
+
+    close_guessing(env);
+    let open: std::vec::Vec<_> = client.open_games().iter().map(|game| game.id).collect();
+    assert_eq!(open, [second]);
+}
+
+#[test]
+fn create_game_rejects_invalid_config() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+    let commitment = commit(env, 1, &salt(env));
 
-    set_caller(&client, "reset", &user, ());
-    assert!(client.try_reset().is_err());
+    let mut past_deadline = config(env, 1, 10, 1);
+    past_deadline.deadline = env.ledger().sequence();
+    let mut no_reveal_window = config(env, 1, 10, 1);
+    no_reveal_window.reveal_deadline = no_reveal_window.deadline;
 
-    set_caller(&client, "reset", &admin, ());
-    assert!(client.try_reset().is_ok());
+    for config in [
+        config(env, 5, 5, 1),
+        config(env, 1, 10, 0),
+        past_deadline,
+        no_reveal_window,
+    ] {
+        assert_eq!(
+            client.try_create_game(&token, &0, &0, &commitment, &config),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+
+    let config = config(env, 1, 10, 1);
+    assert_eq!(
+        client.try_create_game(&token, &-1, &0, &commitment, &config),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_create_game(&token, &0, &-1, &commitment, &config),
+        Err(Ok(Error::InvalidAmount))
+    );
 }
 
 #[test]
-fn one_guess_in_ten_returns_true() {
+fn games_and_guesses_are_bounded() {
     let env = &Env::default();
+    env.mock_all_auths();
 
     let admin = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    let (game_id, _) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 1);
+    for _ in 0..MAX_GUESSES {
+        client.guess(&game_id, &Address::generate(env), &1);
+    }
+    assert_eq!(
+        client.try_guess(&game_id, &Address::generate(env), &1),
+        Err(Ok(Error::RoundFull))
+    );
+
+    for _ in 1..MAX_OPEN_GAMES {
+        start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 1);
+    }
+    let commitment = commit(env, 1, &salt(env));
+    assert_eq!(
+        client.try_create_game(&token, &0, &0, &commitment, &config(env, 1, 10, 1)),
+        Err(Ok(Error::TooManyOpenGames))
+    );
+}
+
+#[test]
+fn game_entries_outlive_the_game() {
+    let env = &Env::default();
+    env.mock_all_auths();
 
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
     let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &100);
 
-    // set the number
-    set_caller(&client, "reset", &admin, ());
-    client.reset();
+    let (game_id, _) = start_game(&client, &token.address, 100, 0, config(env, 1, 10, 1), 3);
+    client.guess(&game_id, &player, &5);
+    close_guessing(env);
+    env.ledger()
+        .with_mut(|li| li.sequence_number += REVEAL_LEDGERS);
+    client.refund(&game_id);
 
-    let trues: std::vec::Vec<_> = (1u64..=10).filter(|number| client.guess(number)).collect();
-    assert_eq!(trues.len(), 1);
+    // every entry written is bumped, so nothing is archived before settling
+    env.as_contract(&client.address, || {
+        for key in [
+            DataKey::Game(game_id),
+            DataKey::Guesses(game_id),
+            DataKey::Attempts(game_id, player.clone()),
+            DataKey::Stats(player.clone()),
+            DataKey::Claimable(game_id, player.clone()),
+        ] {
+            let elapsed = GUESS_LEDGERS + 1 + REVEAL_LEDGERS;
+            assert!(env.storage().persistent().get_ttl(&key) >= TTL_EXTEND_TO - elapsed);
+        }
+    });
+}
+
+#[test]
+fn admin_can_only_be_set_once() {
+    let env = &Env::default();
+
+    let admin = Address::generate(env);
+    let client = generate_client(env, &admin);
+
+    let result = env.as_contract(&client.address, || {
+        GuessTheNumber::set_admin(env, &Address::generate(env))
+    });
+    assert_eq!(result, Err(Error::AdminAlreadySet));
+}
+
+#[test]
+fn stats_track_attempts_wins_and_streaks() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    let games: std::vec::Vec<_> = [3u64, 5, 7]
+        .iter()
+        .map(|number| start_game(&client, &token, 0, 0, config(env, 1, 10, 2), *number))
+        .collect();
+
+    // wins the first two games (twice in the second), misses the third
+    client.guess(&games[0].0, &player, &3);
+    client.guess(&games[1].0, &player, &5);
+    client.guess(&games[1].0, &player, &5);
+    client.guess(&games[2].0, &player, &1);
+    assert_eq!(client.stats(&player).attempts, 4);
+
+    close_guessing(env);
+    client.reveal(&games[0].0, &3, &games[0].1, &None);
+    client.reveal(&games[1].0, &5, &games[1].1, &None);
+    assert_eq!(
+        client.stats(&player),
+        PlayerStats {
+            attempts: 4,
+            wins: 2,
+            streak: 2,
+            best_streak: 2,
+        }
+    );
+
+    client.reveal(&games[2].0, &7, &games[2].1, &None);
+    let stats = client.stats(&player);
+    assert_eq!((stats.wins, stats.streak, stats.best_streak), (2, 0, 2));
+}
+
+#[test]
+fn leaderboard_ranks_players_by_wins() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    // play one game at a time with the given winner
+    let play = |winner: &Address| {
+        let (game_id, salt) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 1);
+        client.guess(&game_id, winner, &1);
+        close_guessing(env);
+        client.reveal(&game_id, &1, &salt, &None);
+    };
+
+    play(&bob);
+    play(&alice);
+    play(&alice);
+    let entry = |player: &Address, wins| LeaderboardEntry {
+        player: player.clone(),
+        wins,
+    };
+    assert_eq!(
+        client.leaderboard(),
+        vec![env, entry(&alice, 2), entry(&bob, 1)]
+    );
+
+    // catching up doesn't overtake whoever got there first
+    play(&bob);
+    assert_eq!(
+        client.leaderboard(),
+        vec![env, entry(&alice, 2), entry(&bob, 2)]
+    );
+
+    // the board keeps only the top players
+    for _ in 0..LEADERBOARD_SIZE {
+        play(&Address::generate(env));
+        play(&alice);
+        play(&bob);
+    }
+    let board = client.leaderboard();
+    assert_eq!(board.len(), LEADERBOARD_SIZE);
+    assert_eq!(board.get(0).unwrap(), entry(&alice, 2 + LEADERBOARD_SIZE));
+    assert_eq!(board.get(1).unwrap(), entry(&bob, 2 + LEADERBOARD_SIZE));
+}
+
+#[test]
+fn guess_and_win_emit_events() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let player = Address::generate(env);
+    let client = generate_client(env, &admin);
+    let (token, token_admin) = create_token(env, &admin);
+    token_admin.mint(&admin, &100);
+
+    let (game_id, salt) = start_game(&client, &token.address, 100, 0, config(env, 1, 10, 1), 4);
+
+    client.guess(&game_id, &player, &4);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            env,
+            (
+                client.address.clone(),
+                (symbol_short!("guess"), game_id, player.clone()).into_val(env),
+                4u64.into_val(env),
+            ),
+        ]
+    );
+
+    close_guessing(env);
+    client.reveal(&game_id, &4, &salt, &None);
+    let win = env.events().all().last().unwrap();
+    assert_eq!(
+        vec![env, win],
+        vec![
+            env,
+            (
+                client.address.clone(),
+                (symbol_short!("win"), game_id, player).into_val(env),
+                100i128.into_val(env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn guess_requires_guesser_auth() {
+    let env = &Env::default();
+
+    let admin = Address::generate(env);
+    let guesser = Address::generate(env);
+    let other = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    let token = Address::generate(env);
+
+    env.mock_all_auths();
+    let (game_id, _) = start_game(&client, &token, 0, 0, config(env, 1, 10, 1), 1);
+
+    set_caller(&client, "guess", &other, (game_id, &guesser, 1u64));
+    assert!(client.try_guess(&game_id, &guesser, &1).is_err());
+
+    set_caller(&client, "guess", &guesser, (game_id, &guesser, 1u64));
+    assert!(client.try_guess(&game_id, &guesser, &1).is_ok());
+}
+
+#[test]
+fn guess_unknown_game_fails() {
+    let env = &Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let guesser = Address::generate(env);
+
+    let client = generate_client(env, &admin);
+    assert_eq!(
+        client.try_guess(&0, &guesser, &1),
+        Err(Ok(Error::NoActiveRound))
+    );
 }
 
 fn generate_client<'a>(env: &Env, admin: &Address) -> GuessTheNumberClient<'a> {
@@ -44,6 +730,62 @@ fn generate_client<'a>(env: &Env, admin: &Address) -> GuessTheNumberClient<'a> {
     GuessTheNumberClient::new(env, &contract_id)
 }
 
+fn create_token<'a>(env: &Env, admin: &Address) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    (
+        TokenClient::new(env, &address),
+        StellarAssetClient::new(env, &address),
+    )
+}
+
+fn config(env: &Env, min: u64, max: u64, max_attempts: u32) -> GameConfig {
+    let deadline = env.ledger().sequence() + GUESS_LEDGERS;
+    GameConfig {
+        min,
+        max,
+        max_attempts,
+        deadline,
+        reveal_deadline: deadline + REVEAL_LEDGERS,
+    }
+}
+
+fn salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[42; 32])
+}
+
+fn commit(env: &Env, number: u64, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &number.to_be_bytes());
+    preimage.append(&Bytes::from(salt));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Create a game committed to `number`, returning its id and the salt to reveal it
+fn start_game(
+    client: &GuessTheNumberClient,
+    token: &Address,
+    amount: i128,
+    entry_fee: i128,
+    config: GameConfig,
+    number: u64,
+) -> (u32, BytesN<32>) {
+    let salt = salt(&client.env);
+    let game_id = client.create_game(
+        token,
+        &amount,
+        &entry_fee,
+        &commit(&client.env, number, &salt),
+        &config,
+    );
+    (game_id, salt)
+}
+
+fn close_guessing(env: &Env) {
+    env.ledger()
+        .with_mut(|li| li.sequence_number += GUESS_LEDGERS + 1);
+}
+
 fn set_caller<T>(client: &GuessTheNumberClient, method: &str, caller: &Address, args: T)
 where
     T: IntoVal<Env, Vec<Val>>,
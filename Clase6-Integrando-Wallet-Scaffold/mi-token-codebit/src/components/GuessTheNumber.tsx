@@ -1,15 +1,37 @@
-import { useState } from "react";
-import { Button, Code, Input, Text } from "@stellar/design-system";
+import { useEffect, useState } from "react";
+import { Button, Code, Input, Select, Text } from "@stellar/design-system";
 import { useWallet } from "../hooks/useWallet";
 import game from "../contracts/guess_the_number";
+import type { Game } from "guess_the_number";
 import { Box } from "../components/layout/Box";
 
-
+// Friendly text for the contract's `Error` enum (the binding's message is the
+// variant name)
+const errorMessages: Record<string, string> = {
+  NoActiveRound: "That game doesn't exist.",
+  RoundClosed: "Guessing is closed for this game.",
+  NumberOutOfRange: "That number is outside this game's range.",
+  TooManyAttempts: "You used all your attempts in this game.",
+  RoundFull: "This game is full.",
+  NothingToClaim: "You have nothing to claim in this game.",
+};
 
 export const GuessTheNumber = () => {
-  const [guessedIt, setGuessedIt] = useState<boolean>();
+  const [submitted, setSubmitted] = useState<boolean>();
   const [theGuess, setTheGuess] = useState<number>();
-  const { address } = useWallet();
+  const [games, setGames] = useState<Game[]>([]);
+  const [gameId, setGameId] = useState<number>();
+  const [error, setError] = useState<string>();
+  const { address, signTransaction } = useWallet();
+
+  useEffect(() => {
+    void game.open_games().then(({ result }) => {
+      setGames(result);
+      setGameId(result[0]?.id);
+    });
+  }, []);
+
+  const selected = games.find((g) => g.id === gameId);
 
   if (!address) {
     return (
@@ -20,9 +42,22 @@ export const GuessTheNumber = () => {
   }
 
   const submitGuess = async () => {
-    if (!theGuess) return;
-    const { result } = await game.guess({ a_number: BigInt(theGuess) });
-    setGuessedIt(result);
+    if (!theGuess || !signTransaction || gameId === undefined) return;
+    // guess requires the guesser's signature (it may charge an entry fee)
+    game.options.publicKey = address;
+    const tx = await game.guess({
+      game_id: gameId,
+      guesser: address,
+      a_number: BigInt(theGuess),
+    });
+    // contract errors show up in the simulation, before asking for a signature
+    if (tx.result.isErr()) {
+      const { message } = tx.result.unwrapErr();
+      setError(errorMessages[message] ?? message);
+      return;
+    }
+    await tx.signAndSend({ signTransaction });
+    setSubmitted(true);
   };
 
   return (
@@ -32,25 +67,53 @@ export const GuessTheNumber = () => {
         void submitGuess();
       }}
     >
-      {guessedIt ? (
+      {submitted ? (
         <>
           <Text as="p" size="lg">
-            You got it!
+            Guess recorded!
           </Text>
           <Text as="p" size="lg">
-            Set a new number by calling <Code size="md">reset</Code> from the
-            CLI as the admin.
+            Once guessing closes the admin calls{" "}
+            <Code size="md">reveal</Code>: the first correct guess takes the
+            whole pot (if nobody gets it, it rolls into the next round, which
+            can start right away). If they never reveal, call{" "}
+            <Code size="md">refund</Code> to get your entry fee back plus a
+            share of the pot. Either way, collect with{" "}
+            <Code size="md">claim</Code>. The admin knows the number, so only
+            play on tables run by someone you trust not to guess from another
+            account.
           </Text>
         </>
+      ) : !selected ? (
+        <Text as="p" size="lg">
+          No open games right now. The admin starts one by calling{" "}
+          <Code size="md">create_game</Code> from the CLI.
+        </Text>
       ) : (
         <Box gap="sm" direction="row" align="end" justify="end" wrap="wrap">
+          <Select
+            id="game"
+            fieldSize="lg"
+            label="Game"
+            value={String(gameId)}
+            onChange={(e) => {
+              setError(undefined);
+              setGameId(Number(e.target.value));
+            }}
+          >
+            {games.map((g) => (
+              <option key={g.id} value={g.id}>
+                #{g.id} ({String(g.config.min)} to {String(g.config.max)})
+              </option>
+            ))}
+          </Select>
           <Input
-            label="Guess a number from 1 to 10!"
+            label={`Guess a number from ${String(selected.config.min)} to ${String(selected.config.max)}!`}
             id="guess"
             fieldSize="lg"
-            error={guessedIt === false && "Wrong! Guess again."}
+            error={error}
             onChange={(e) => {
-              setGuessedIt(undefined);
+              setError(undefined);
               setTheGuess(Number(e.target.value));
             }}
           />
@@ -0,0 +1,150 @@
+// src/allowance.rs
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Máximo de spenders con allowance activa por owner
+/// revoke_all() recorre el índice completo, así que se mantiene acotado
+const MAX_SPENDERS: u32 = 50;
+
+/// Máximo de spenders por página
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Trait de allowances enumerables
+///
+/// Cada owner tiene un índice de los spenders con allowance > 0, así
+/// puede ver y limpiar todos los bots que alguna vez aprobó.
+///
+/// Las allowances creadas antes del índice no figuran en él hasta que se
+/// vuelven a escribir (approve, transfer_from, increase/decrease) con lugar
+/// en el índice: hasta entonces revoke_all() no las ve y hay que revocarlas
+/// con approve(0).
+pub trait AllowanceTrait {
+    /// Suma `amount` a la allowance actual (evita la carrera de approve)
+    fn increase_allowance(env: Env, owner: Address, spender: Address, amount: i128) -> Result<(), CodebitError>;
+
+    /// Resta `amount` de la allowance actual (falla si no alcanza)
+    fn decrease_allowance(env: Env, owner: Address, spender: Address, amount: i128) -> Result<(), CodebitError>;
+
+    /// Revoca todas las allowances indexadas del owner
+    fn revoke_all(env: Env, owner: Address) -> Result<u32, CodebitError>;
+
+    /// Spenders con allowance activa, paginados
+    fn spenders_of(env: Env, owner: Address, start: u32, limit: u32) -> Vec<Address>;
+}
+
+#[contractimpl]
+impl AllowanceTrait for TokenCodebit {
+    fn increase_allowance(env: Env, owner: Address, spender: Address, amount: i128) -> Result<(), CodebitError> {
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(CodebitError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        let new_allowance = read_allowance(&env, &owner, &spender)
+            .checked_add(amount)
+            .ok_or(CodebitError::OverflowError)?;
+        write_allowance(&env, &owner, &spender, new_allowance)
+    }
+
+    fn decrease_allowance(env: Env, owner: Address, spender: Address, amount: i128) -> Result<(), CodebitError> {
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(CodebitError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        let allowed = read_allowance(&env, &owner, &spender);
+        if allowed < amount {
+            return Err(CodebitError::InsufficientAllowance);
+        }
+        write_allowance(&env, &owner, &spender, allowed - amount)
+    }
+
+    fn revoke_all(env: Env, owner: Address) -> Result<u32, CodebitError> {
+        owner.require_auth();
+
+        let spenders = read_spenders(&env, &owner);
+        for spender in spenders.iter() {
+            env.storage().persistent().remove(&DataKey::Allowance(owner.clone(), spender));
+        }
+        env.storage().persistent().remove(&DataKey::Spenders(owner));
+
+        Ok(spenders.len())
+    }
+
+    fn spenders_of(env: Env, owner: Address, start: u32, limit: u32) -> Vec<Address> {
+        let spenders = read_spenders(&env, &owner);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(spenders.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        spenders.slice(start..end)
+    }
+}
+
+pub fn read_allowance(env: &Env, owner: &Address, spender: &Address) -> i128 {
+    env.storage().persistent()
+        .get(&DataKey::Allowance(owner.clone(), spender.clone()))
+        .unwrap_or(0)
+}
+
+/// Escribe la allowance y mantiene el índice de spenders del owner
+/// Allowance 0 => se elimina la entrada y el spender sale del índice
+pub fn write_allowance(env: &Env, owner: &Address, spender: &Address, amount: i128) -> Result<(), CodebitError> {
+    let key = DataKey::Allowance(owner.clone(), spender.clone());
+    let mut spenders = read_spenders(env, owner);
+    let index = spenders.first_index_of(spender);
+
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+        if let Some(index) = index {
+            spenders.remove(index);
+            write_spenders(env, owner, &spenders);
+        }
+        return Ok(());
+    }
+
+    if index.is_none() {
+        if spenders.len() < MAX_SPENDERS {
+            spenders.push_back(spender.clone());
+            write_spenders(env, owner, &spenders);
+        } else if !env.storage().persistent().has(&key) {
+            return Err(CodebitError::SpenderLimit);
+        }
+        // Con el índice lleno, una allowance previa al índice se actualiza
+        // sin indexarse (el índice nunca pasa de MAX_SPENDERS)
+    }
+
+    env.storage().persistent().set(&key, &amount);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+
+    Ok(())
+}
+
+fn read_spenders(env: &Env, owner: &Address) -> Vec<Address> {
+    env.storage().persistent()
+        .get(&DataKey::Spenders(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn write_spenders(env: &Env, owner: &Address, spenders: &Vec<Address>) {
+    let key = DataKey::Spenders(owner.clone());
+    if spenders.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, spenders);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    }
+}
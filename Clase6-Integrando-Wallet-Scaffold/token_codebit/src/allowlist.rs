@@ -0,0 +1,137 @@
+// src/allowlist.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_admin, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Configuración del modo allowlist
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistConfig {
+    /// Si true, también el sender debe estar en la allowlist
+    pub restrict_senders: bool,
+}
+
+/// Trait del modo allowlist (cohortes cerradas)
+///
+/// Con el modo activo, `mint`, `transfer` y `transfer_from` solo acreditan
+/// a miembros verificados. El admin nombra registrars que pueden
+/// agregar o quitar miembros en lote.
+pub trait AllowlistTrait {
+    /// El admin activa/desactiva el modo (y la restricción de senders)
+    fn set_allowlist_mode(env: Env, enabled: bool, restrict_senders: bool) -> Result<(), CodebitError>;
+
+    /// El admin agrega o quita un registrar
+    fn set_registrar(env: Env, registrar: Address, enabled: bool) -> Result<(), CodebitError>;
+
+    /// Admin o registrar agregan miembros
+    fn add_members(env: Env, caller: Address, accounts: Vec<Address>) -> Result<(), CodebitError>;
+
+    /// Admin o registrar quitan miembros
+    fn remove_members(env: Env, caller: Address, accounts: Vec<Address>) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn allowlist_mode(env: Env) -> Option<AllowlistConfig>;
+    fn is_allowlisted(env: Env, account: Address) -> bool;
+    fn is_registrar(env: Env, account: Address) -> bool;
+}
+
+#[contractimpl]
+impl AllowlistTrait for TokenCodebit {
+    fn set_allowlist_mode(env: Env, enabled: bool, restrict_senders: bool) -> Result<(), CodebitError> {
+        require_admin(&env)?;
+
+        if enabled {
+            env.storage().instance().set(&DataKey::Allowlist, &AllowlistConfig { restrict_senders });
+        } else {
+            env.storage().instance().remove(&DataKey::Allowlist);
+        }
+
+        Ok(())
+    }
+
+    fn set_registrar(env: Env, registrar: Address, enabled: bool) -> Result<(), CodebitError> {
+        require_admin(&env)?;
+        write_flag(&env, DataKey::Registrar(registrar), enabled);
+        Ok(())
+    }
+
+    fn add_members(env: Env, caller: Address, accounts: Vec<Address>) -> Result<(), CodebitError> {
+        require_registrar(&env, &caller)?;
+        for account in accounts.iter() {
+            write_flag(&env, DataKey::Allowlisted(account), true);
+        }
+        Ok(())
+    }
+
+    fn remove_members(env: Env, caller: Address, accounts: Vec<Address>) -> Result<(), CodebitError> {
+        require_registrar(&env, &caller)?;
+        for account in accounts.iter() {
+            write_flag(&env, DataKey::Allowlisted(account), false);
+        }
+        Ok(())
+    }
+
+    fn allowlist_mode(env: Env) -> Option<AllowlistConfig> {
+        read_config(&env)
+    }
+
+    fn is_allowlisted(env: Env, account: Address) -> bool {
+        is_member(&env, &account)
+    }
+
+    fn is_registrar(env: Env, account: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Registrar(account))
+    }
+}
+
+/// Hook de mint/transfer/transfer_from: falla con NotAllowlisted si el
+/// modo está activo y el recipient (o el sender, si se restringe) no es miembro
+pub fn check(env: &Env, from: Option<&Address>, to: &Address) -> Result<(), CodebitError> {
+    let Some(config) = read_config(env) else {
+        return Ok(());
+    };
+
+    if !is_member(env, to) {
+        return Err(CodebitError::NotAllowlisted);
+    }
+
+    if config.restrict_senders && from.is_some_and(|from| !is_member(env, from)) {
+        return Err(CodebitError::NotAllowlisted);
+    }
+
+    Ok(())
+}
+
+fn read_config(env: &Env) -> Option<AllowlistConfig> {
+    env.storage().instance().get(&DataKey::Allowlist)
+}
+
+/// La custodia del contrato (streams, staking) siempre está habilitada
+fn is_member(env: &Env, account: &Address) -> bool {
+    *account == env.current_contract_address()
+        || env.storage().persistent().has(&DataKey::Allowlisted(account.clone()))
+}
+
+/// Registrar con su firma, o el admin (o el consejo si está activo)
+fn require_registrar(env: &Env, caller: &Address) -> Result<(), CodebitError> {
+    if env.storage().persistent().has(&DataKey::Registrar(caller.clone())) {
+        caller.require_auth();
+        return Ok(());
+    }
+
+    if require_admin(env)? != *caller {
+        return Err(CodebitError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn write_flag(env: &Env, key: DataKey, enabled: bool) {
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
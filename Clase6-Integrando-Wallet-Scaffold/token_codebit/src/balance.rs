@@ -0,0 +1,76 @@
+// src/balance.rs
+use soroban_sdk::{Address, Env};
+
+use crate::{demurrage, mutual_credit, rewards};
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+
+/// Lee el balance de CODEBIT de una cuenta (0 si no existe)
+/// Con demurrage activo devuelve el balance ya decaído
+pub fn read_balance(env: &Env, account: &Address) -> i128 {
+    let balance = read_stored_balance(env, account);
+    demurrage::decayed_balance(env, account, balance)
+}
+
+/// Balance tal como está guardado (sin aplicar demurrage pendiente)
+pub fn read_stored_balance(env: &Env, account: &Address) -> i128 {
+    env.storage().persistent()
+        .get(&DataKey::Balance(account.clone()))
+        .unwrap_or(0)
+}
+
+/// Escribe el balance de una cuenta
+/// Balance 0 => se elimina la entrada para no pagar renta de storage
+pub fn write_balance(env: &Env, account: &Address, amount: i128) {
+    let key = DataKey::Balance(account.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    }
+}
+
+/// Suma CODEBIT al balance de una cuenta con protección de overflow
+pub fn receive_balance(env: &Env, account: &Address, amount: i128) -> Result<(), CodebitError> {
+    let balance = demurrage::settle_account(env, account);
+    let new_balance = balance.checked_add(amount)
+        .ok_or(CodebitError::OverflowError)?;
+    rewards::checkpoint(env, account, balance);
+    write_balance(env, account, new_balance);
+    demurrage::on_receive(env, account, amount);
+    mutual_credit::on_balance_change(env, balance, new_balance);
+    Ok(())
+}
+
+/// Resta CODEBIT del balance de una cuenta
+/// Falla con InsufficientBalance si no alcanza
+pub fn spend_balance(env: &Env, account: &Address, amount: i128) -> Result<(), CodebitError> {
+    let balance = demurrage::settle_account(env, account);
+    if balance < amount {
+        return Err(CodebitError::InsufficientBalance);
+    }
+    rewards::checkpoint(env, account, balance);
+    write_balance(env, account, balance - amount);
+    demurrage::on_spend(env, account, amount);
+    Ok(())
+}
+
+/// Igual que spend_balance pero, en modo crédito mutuo, permite bajar
+/// de 0 hasta el límite de crédito de la cuenta (transfer/transfer_from)
+pub fn spend_balance_with_credit(env: &Env, account: &Address, amount: i128) -> Result<(), CodebitError> {
+    if !mutual_credit::is_enabled(env) {
+        return spend_balance(env, account, amount);
+    }
+
+    let balance = read_stored_balance(env, account);
+    let new_balance = balance.checked_sub(amount)
+        .ok_or(CodebitError::OverflowError)?;
+    if new_balance < -mutual_credit::read_credit_limit(env, account) {
+        return Err(CodebitError::CreditLimitExceeded);
+    }
+    rewards::checkpoint(env, account, balance);
+    write_balance(env, account, new_balance);
+    mutual_credit::on_balance_change(env, balance, new_balance);
+    Ok(())
+}
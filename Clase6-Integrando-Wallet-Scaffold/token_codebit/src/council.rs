@@ -0,0 +1,269 @@
+// src/council.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::staking::StakingConfig;
+use crate::timelock::{self, TimelockAction};
+use crate::{
+    require_config_admin, AllowlistTrait, CodebitTrait, DemurrageTrait, MutualCreditTrait, RateLimitTrait,
+    RewardsTrait, StakingTrait, TimelockTrait, TokenCodebit, TokenCodebitArgs, TokenCodebitClient
+};
+
+/// Consejo M-of-N que reemplaza al admin único
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    /// Se incrementa en cada rotación e invalida propuestas anteriores
+    pub epoch: u32,
+}
+
+/// Operaciones privilegiadas que el consejo puede proponer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    Mint(Address, i128),
+    SetDemurrageRate(u32),
+    SetDemurrageExempt(Address, bool),
+    SetMutualCredit(bool),
+    SetCreditLimit(Address, i128),
+    SetStakingConfig(StakingConfig),
+    /// (max_amount, window_ledgers)
+    SetDefaultRateLimit(i128, u32),
+    /// Quema CODEBIT en stake de una cuenta
+    Slash(Address, i128),
+    /// (enabled, restrict_senders)
+    SetAllowlistMode(bool, bool),
+    SetRegistrar(Address, bool),
+    AddRewardToken(Address),
+    /// Nuevos firmantes y threshold (con el timelock activo va por QueueTimelock)
+    RotateSigners(Vec<Address>, u32),
+    /// Encola una acción en el timelock con su eta
+    QueueTimelock(TimelockAction, u32),
+    /// Cancela una operación encolada en el timelock
+    CancelTimelock(u64),
+    /// Vuelve al admin único guardado en DataKey::Admin
+    /// (con el timelock activo va por QueueTimelock)
+    DisableCouncil,
+}
+
+/// Propuesta pendiente de aprobación
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub action: CouncilAction,
+    pub approvals: Vec<Address>,
+    pub epoch: u32,
+}
+
+/// Trait del consejo multisig para operaciones privilegiadas
+///
+/// Con el consejo activo, `mint` y los cambios de configuración ya no
+/// aceptan la firma del admin: solo se ejecutan vía `execute_proposal`
+/// cuando la propuesta reúne `threshold` aprobaciones de firmantes.
+/// Con el timelock activo, instalar, rotar o desactivar el consejo
+/// también pasa por la cola del timelock.
+pub trait CouncilTrait {
+    /// El admin actual delega sus permisos en un consejo M-of-N
+    /// (sensible: con el timelock activo se encola como EnableCouncil)
+    fn enable_council(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), CodebitError>;
+
+    /// Un firmante propone una acción (cuenta como su aprobación)
+    fn propose(env: Env, proposer: Address, action: CouncilAction) -> Result<u64, CodebitError>;
+
+    /// Otro firmante aprueba la propuesta
+    fn approve_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), CodebitError>;
+
+    /// Ejecuta la propuesta si alcanzó el threshold
+    fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn council(env: Env) -> Option<Council>;
+    fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, CodebitError>;
+}
+
+#[contractimpl]
+impl CouncilTrait for TokenCodebit {
+    fn enable_council(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), CodebitError> {
+        if is_enabled(&env) {
+            return Err(CodebitError::InvalidConfig);
+        }
+        require_config_admin(&env)?;
+
+        write_council(&env, signers, threshold, 0)
+    }
+
+    fn propose(env: Env, proposer: Address, action: CouncilAction) -> Result<u64, CodebitError> {
+        let council = read_council(&env).ok_or(CodebitError::ModeDisabled)?;
+        require_signer(&council, &proposer)?;
+
+        let proposal_id: u64 = env.storage().instance()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::ProposalCount, &(proposal_id + 1));
+
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer);
+        write_proposal(&env, proposal_id, &Proposal { action, approvals, epoch: council.epoch });
+
+        Ok(proposal_id)
+    }
+
+    fn approve_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), CodebitError> {
+        let council = read_council(&env).ok_or(CodebitError::ModeDisabled)?;
+        require_signer(&council, &signer)?;
+
+        let mut proposal = read_proposal(&env, proposal_id)?;
+        if proposal.epoch != council.epoch {
+            return Err(CodebitError::ProposalNotFound);
+        }
+
+        if proposal.approvals.contains(&signer) {
+            return Err(CodebitError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer);
+        write_proposal(&env, proposal_id, &proposal);
+
+        Ok(())
+    }
+
+    fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), CodebitError> {
+        let council = read_council(&env).ok_or(CodebitError::ModeDisabled)?;
+        let proposal = read_proposal(&env, proposal_id)?;
+
+        // Propuestas de un consejo anterior ya no valen
+        if proposal.epoch != council.epoch {
+            return Err(CodebitError::ProposalNotFound);
+        }
+
+        if proposal.approvals.len() < council.threshold {
+            return Err(CodebitError::ThresholdNotMet);
+        }
+
+        env.storage().persistent().remove(&DataKey::Proposal(proposal_id));
+
+        // require_admin() acepta la operación solo mientras dura este flag
+        env.storage().temporary().set(&DataKey::CouncilExecuting, &true);
+        let result = match proposal.action {
+            CouncilAction::Mint(to, amount) =>
+                TokenCodebit::mint(env.clone(), to, amount),
+            CouncilAction::SetDemurrageRate(rate_bps) =>
+                TokenCodebit::set_demurrage_rate(env.clone(), rate_bps),
+            CouncilAction::SetDemurrageExempt(account, exempt) =>
+                TokenCodebit::set_demurrage_exempt(env.clone(), account, exempt),
+            CouncilAction::SetMutualCredit(enabled) =>
+                TokenCodebit::set_mutual_credit(env.clone(), enabled),
+            CouncilAction::SetCreditLimit(account, limit) =>
+                TokenCodebit::set_credit_limit(env.clone(), account, limit),
+            CouncilAction::SetStakingConfig(config) =>
+                TokenCodebit::set_staking_config(env.clone(), config),
+            CouncilAction::SetDefaultRateLimit(max_amount, window_ledgers) =>
+                TokenCodebit::set_default_rate_limit(env.clone(), max_amount, window_ledgers),
+            CouncilAction::Slash(account, amount) =>
+                TokenCodebit::slash(env.clone(), account, amount),
+            CouncilAction::SetAllowlistMode(enabled, restrict_senders) =>
+                TokenCodebit::set_allowlist_mode(env.clone(), enabled, restrict_senders),
+            CouncilAction::SetRegistrar(registrar, enabled) =>
+                TokenCodebit::set_registrar(env.clone(), registrar, enabled),
+            CouncilAction::AddRewardToken(reward_token) =>
+                TokenCodebit::add_reward_token(env.clone(), reward_token),
+            CouncilAction::RotateSigners(signers, threshold) =>
+                rotate_signers(&env, signers, threshold),
+            CouncilAction::QueueTimelock(action, eta) =>
+                TokenCodebit::queue_admin_action(env.clone(), action, eta).map(|_| ()),
+            CouncilAction::CancelTimelock(operation_id) =>
+                TokenCodebit::cancel_admin_action(env.clone(), operation_id),
+            CouncilAction::DisableCouncil =>
+                disable_council(&env),
+        };
+        env.storage().temporary().remove(&DataKey::CouncilExecuting);
+
+        result
+    }
+
+    fn council(env: Env) -> Option<Council> {
+        read_council(&env)
+    }
+
+    fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, CodebitError> {
+        read_proposal(&env, proposal_id)
+    }
+}
+
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Council)
+}
+
+/// true solo dentro de execute_proposal()
+pub fn is_executing(env: &Env) -> bool {
+    env.storage().temporary().has(&DataKey::CouncilExecuting)
+}
+
+/// Nuevos firmantes; invalida las propuestas del consejo anterior
+pub fn rotate_signers(env: &Env, signers: Vec<Address>, threshold: u32) -> Result<(), CodebitError> {
+    require_timelock_path(env)?;
+    let council = read_council(env).ok_or(CodebitError::ModeDisabled)?;
+    write_council(env, signers, threshold, council.epoch + 1)
+}
+
+/// Vuelve al admin único guardado en DataKey::Admin
+pub fn disable_council(env: &Env) -> Result<(), CodebitError> {
+    require_timelock_path(env)?;
+    if !is_enabled(env) {
+        return Err(CodebitError::ModeDisabled);
+    }
+    env.storage().instance().remove(&DataKey::Council);
+    Ok(())
+}
+
+/// Con el timelock activo el gobierno solo cambia dentro de execute_admin_action()
+fn require_timelock_path(env: &Env) -> Result<(), CodebitError> {
+    if timelock::is_enabled(env) && !timelock::is_executing(env) {
+        return Err(CodebitError::TimelockRequired);
+    }
+    Ok(())
+}
+
+fn read_council(env: &Env) -> Option<Council> {
+    env.storage().instance().get(&DataKey::Council)
+}
+
+/// Valida y guarda firmantes/threshold
+fn write_council(env: &Env, signers: Vec<Address>, threshold: u32, epoch: u32) -> Result<(), CodebitError> {
+    if threshold == 0 || threshold > signers.len() {
+        return Err(CodebitError::InvalidConfig);
+    }
+
+    // Sin firmantes duplicados (un firmante no puede contar dos veces)
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            return Err(CodebitError::InvalidConfig);
+        }
+    }
+
+    env.storage().instance().set(&DataKey::Council, &Council { signers, threshold, epoch });
+    Ok(())
+}
+
+fn require_signer(council: &Council, signer: &Address) -> Result<(), CodebitError> {
+    signer.require_auth();
+    if !council.signers.contains(signer) {
+        return Err(CodebitError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn read_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, CodebitError> {
+    env.storage().persistent()
+        .get(&DataKey::Proposal(proposal_id))
+        .ok_or(CodebitError::ProposalNotFound)
+}
+
+fn write_proposal(env: &Env, proposal_id: u64, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
@@ -0,0 +1,282 @@
+// src/demurrage.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env, String};
+
+use crate::balance::{read_stored_balance, write_balance};
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_config_admin, CodebitTrait, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// 100% expresado en basis points
+const MAX_RATE_BPS: u32 = 10_000;
+
+/// Escala de punto fijo para calcular el factor de decaimiento
+const SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// Configuración de demurrage (decaimiento de minutos sin usar)
+///
+/// Cada `period_ledgers` ledgers los balances no exentos pierden
+/// `rate_bps / 10_000` de su valor (interés compuesto negativo).
+/// Los períodos están alineados globalmente (ledger / period_ledgers)
+/// para que todos los balances decaigan en el mismo instante.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DemurrageConfig {
+    pub rate_bps: u32,
+    pub period_ledgers: u32,
+}
+
+/// Supply sujeto a demurrage (suma de balances no exentos)
+/// junto con el ledger en que se liquidó por última vez
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecayingSupply {
+    pub amount: i128,
+    pub last_ledger: u32,
+}
+
+/// Trait del modo demurrage (banco de tiempo: los minutos ociosos decaen)
+pub trait DemurrageTrait {
+    /// Igual que initialize() pero activando el modo demurrage
+    fn initialize_with_demurrage(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        rate_bps: u32,
+        period_ledgers: u32
+    ) -> Result<(), CodebitError>;
+
+    /// Cambia la tasa de decaimiento (solo admin)
+    /// La nueva tasa también se aplica al decaimiento aún no liquidado
+    fn set_demurrage_rate(env: Env, rate_bps: u32) -> Result<(), CodebitError>;
+
+    /// Marca o desmarca una cuenta como exenta de demurrage (solo admin)
+    fn set_demurrage_exempt(env: Env, account: Address, exempt: bool) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn demurrage_config(env: Env) -> Option<DemurrageConfig>;
+    fn is_demurrage_exempt(env: Env, account: Address) -> bool;
+}
+
+#[contractimpl]
+impl DemurrageTrait for TokenCodebit {
+    fn initialize_with_demurrage(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        rate_bps: u32,
+        period_ledgers: u32
+    ) -> Result<(), CodebitError> {
+        // 1. Validar la configuración antes de inicializar
+        if rate_bps > MAX_RATE_BPS || period_ledgers == 0 {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        // 2. Inicialización normal de DevPoints
+        <TokenCodebit as CodebitTrait>::initialize(env.clone(), admin, name, symbol, decimals)?;
+
+        // 3. Activar demurrage con el supply decreciente en 0
+        let config = DemurrageConfig { rate_bps, period_ledgers };
+        env.storage().instance().set(&DataKey::Demurrage, &config);
+        env.storage().instance().set(&DataKey::DecayingSupply, &DecayingSupply {
+            amount: 0,
+            last_ledger: env.ledger().sequence(),
+        });
+
+        Ok(())
+    }
+
+    fn set_demurrage_rate(env: Env, rate_bps: u32) -> Result<(), CodebitError> {
+        let mut config = read_config(&env).ok_or(CodebitError::ModeDisabled)?;
+        require_config_admin(&env)?;
+
+        if rate_bps > MAX_RATE_BPS {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        config.rate_bps = rate_bps;
+        env.storage().instance().set(&DataKey::Demurrage, &config);
+
+        Ok(())
+    }
+
+    fn set_demurrage_exempt(env: Env, account: Address, exempt: bool) -> Result<(), CodebitError> {
+        if read_config(&env).is_none() {
+            return Err(CodebitError::ModeDisabled);
+        }
+        require_config_admin(&env)?;
+
+        // La custodia del contrato es siempre exenta
+        if account == env.current_contract_address() {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        if is_exempt(&env, &account) == exempt {
+            return Ok(());
+        }
+
+        // 1. Liquidar el decaimiento pendiente con el estado anterior
+        let balance = settle_account(&env, &account);
+
+        // 2. Mover el balance entre el supply exento y el decreciente
+        if exempt {
+            adjust_decaying_supply(&env, -balance);
+            env.storage().persistent().remove(&DataKey::LastTouched(account.clone()));
+            env.storage().persistent().set(&DataKey::DemurrageExempt(account.clone()), &true);
+            env.storage().persistent().extend_ttl(
+                &DataKey::DemurrageExempt(account),
+                100_000,
+                200_000
+            );
+        } else {
+            env.storage().persistent().remove(&DataKey::DemurrageExempt(account.clone()));
+            adjust_decaying_supply(&env, balance);
+            touch(&env, &account, balance);
+        }
+
+        Ok(())
+    }
+
+    fn demurrage_config(env: Env) -> Option<DemurrageConfig> {
+        read_config(&env)
+    }
+
+    fn is_demurrage_exempt(env: Env, account: Address) -> bool {
+        is_exempt(&env, &account)
+    }
+}
+
+/// Balance con el decaimiento pendiente aplicado (solo lectura)
+pub fn decayed_balance(env: &Env, account: &Address, balance: i128) -> i128 {
+    let Some(config) = read_config(env) else {
+        return balance;
+    };
+    if balance == 0 || is_exempt(env, account) {
+        return balance;
+    }
+
+    let last_ledger: u32 = env.storage().persistent()
+        .get(&DataKey::LastTouched(account.clone()))
+        .unwrap_or(env.ledger().sequence());
+    decay(env, &config, balance, last_ledger)
+}
+
+/// Aplica (escribe) el decaimiento pendiente de una cuenta
+/// Devuelve el balance resultante. Sin demurrage no hace nada.
+pub fn settle_account(env: &Env, account: &Address) -> i128 {
+    let stored = read_stored_balance(env, account);
+    if read_config(env).is_none() || is_exempt(env, account) {
+        return stored;
+    }
+
+    let balance = decayed_balance(env, account, stored);
+    if balance != stored {
+        write_balance(env, account, balance);
+    }
+    touch(env, account, balance);
+    balance
+}
+
+/// Hook de balance: la cuenta recibió `amount` CODEBIT
+pub fn on_receive(env: &Env, account: &Address, amount: i128) {
+    if read_config(env).is_some() && !is_exempt(env, account) {
+        adjust_decaying_supply(env, amount);
+        touch(env, account, read_stored_balance(env, account));
+    }
+}
+
+/// Hook de balance: la cuenta gastó `amount` CODEBIT
+pub fn on_spend(env: &Env, account: &Address, amount: i128) {
+    if read_config(env).is_some() && !is_exempt(env, account) {
+        adjust_decaying_supply(env, -amount);
+        touch(env, account, read_stored_balance(env, account));
+    }
+}
+
+/// Supply total con el decaimiento pendiente del supply decreciente
+///
+/// TotalSupply guarda el supply con el decaimiento liquidado hasta
+/// `DecayingSupply.last_ledger`; acá se descuenta lo que decayó desde entonces.
+pub fn current_total_supply(env: &Env, stored_total: i128) -> i128 {
+    let Some(config) = read_config(env) else {
+        return stored_total;
+    };
+    let supply = read_decaying_supply(env);
+    let decayed = decay(env, &config, supply.amount, supply.last_ledger);
+    stored_total - (supply.amount - decayed)
+}
+
+/// Liquida el decaimiento del supply decreciente y lo ajusta en `delta`
+fn adjust_decaying_supply(env: &Env, delta: i128) {
+    let Some(config) = read_config(env) else {
+        return;
+    };
+    let supply = read_decaying_supply(env);
+    let decayed = decay(env, &config, supply.amount, supply.last_ledger);
+
+    // Lo que decayó sale del total supply
+    let total: i128 = env.storage().instance()
+        .get(&DataKey::TotalSupply)
+        .unwrap_or(0);
+    env.storage().instance().set(&DataKey::TotalSupply, &(total - (supply.amount - decayed)));
+
+    // El redondeo por cuenta puede dejar el agregado apenas por debajo
+    env.storage().instance().set(&DataKey::DecayingSupply, &DecayingSupply {
+        amount: (decayed + delta).max(0),
+        last_ledger: env.ledger().sequence(),
+    });
+}
+
+/// Registra el ledger en que se tocó por última vez el balance
+fn touch(env: &Env, account: &Address, balance: i128) {
+    let key = DataKey::LastTouched(account.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &env.ledger().sequence());
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    }
+}
+
+/// amount * (1 - rate)^periods, con períodos alineados globalmente
+fn decay(env: &Env, config: &DemurrageConfig, amount: i128, last_ledger: u32) -> i128 {
+    let periods = env.ledger().sequence() / config.period_ledgers
+        - last_ledger / config.period_ledgers;
+    if amount <= 0 || periods == 0 || config.rate_bps == 0 {
+        return amount;
+    }
+
+    // Exponenciación por cuadrados en punto fijo
+    let mut base = SCALE * (MAX_RATE_BPS - config.rate_bps) as i128 / MAX_RATE_BPS as i128;
+    let mut factor = SCALE;
+    let mut exp = periods;
+    while exp > 0 && factor > 0 {
+        if exp & 1 == 1 {
+            factor = factor * base / SCALE;
+        }
+        base = base * base / SCALE;
+        exp >>= 1;
+    }
+
+    // amount * factor / SCALE sin overflow (factor <= SCALE)
+    (amount / SCALE) * factor + (amount % SCALE) * factor / SCALE
+}
+
+fn read_config(env: &Env) -> Option<DemurrageConfig> {
+    env.storage().instance().get(&DataKey::Demurrage)
+}
+
+fn read_decaying_supply(env: &Env) -> DecayingSupply {
+    env.storage().instance()
+        .get(&DataKey::DecayingSupply)
+        .unwrap_or(DecayingSupply { amount: 0, last_ledger: env.ledger().sequence() })
+}
+
+fn is_exempt(env: &Env, account: &Address) -> bool {
+    // La custodia del propio contrato (streams) nunca decae
+    *account == env.current_contract_address()
+        || env.storage().persistent().has(&DataKey::DemurrageExempt(account.clone()))
+}
@@ -44,4 +44,80 @@ pub enum TokenError {
     /// Nombre o símbolo inválido (vacío o muy largo)
     /// Validación de metadatos en initialize()
     InvalidMetadata = 9,
+
+    /// El stream solicitado no existe
+    /// Nunca se creó o ya fue cancelado/liquidado
+    StreamNotFound = 10,
+
+    /// Calendario de stream inválido
+    /// start debe ser < stop y no puede estar en el pasado
+    InvalidSchedule = 11,
+
+    /// La cuenta no tiene permiso para esta operación
+    /// Ej: cancelar un stream del que no es payer ni recipient
+    Unauthorized = 12,
+
+    /// Configuración inválida de un modo opcional
+    /// Ej: tasa de demurrage > 100% o período 0
+    InvalidConfig = 13,
+
+    /// El modo opcional no está activo en este token
+    /// Ej: set_demurrage_rate() sin initialize_with_demurrage()
+    ModeDisabled = 14,
+
+    /// La operación excede el límite de crédito de la cuenta
+    /// Solo en modo crédito mutuo
+    CreditLimitExceeded = 15,
+
+    /// mint() está deshabilitado en modo crédito mutuo
+    MintDisabled = 16,
+
+    /// Se alcanzó el máximo de tokens de recompensa distintos
+    RewardTokenLimit = 17,
+
+    /// No hay holders de CODEBIT entre quienes repartir
+    NoEligibleHolders = 18,
+
+    /// Con el consejo activo la operación debe pasar por una propuesta
+    CouncilRequired = 19,
+
+    /// La propuesta no existe, ya se ejecutó o es de un consejo anterior
+    ProposalNotFound = 20,
+
+    /// El firmante ya aprobó esta propuesta
+    AlreadyApproved = 21,
+
+    /// La propuesta todavía no tiene suficientes aprobaciones
+    ThresholdNotMet = 22,
+
+    /// Con el timelock activo la operación debe encolarse primero
+    TimelockRequired = 23,
+
+    /// El eta no respeta el delay mínimo del timelock
+    TimelockDelayTooShort = 24,
+
+    /// La operación encolada todavía no llegó a su eta
+    TimelockNotReady = 25,
+
+    /// Pasó la ventana de gracia de la operación encolada
+    TimelockExpired = 26,
+
+    /// La operación no existe, ya se ejecutó o fue cancelada
+    OperationNotFound = 27,
+
+    /// Los CODEBIT en unstake todavía no cumplieron el cooldown
+    CooldownActive = 28,
+
+    /// Modo allowlist: el recipient (o el sender) no es miembro verificado
+    NotAllowlisted = 29,
+
+    /// El envío supera el límite de velocidad de la cuenta
+    RateLimitExceeded = 30,
+
+    /// El owner alcanzó el máximo de spenders con allowance activa
+    SpenderLimit = 31,
+
+    /// El token de recompensa no fue habilitado por el admin
+    /// Ver add_reward_token()
+    RewardTokenNotListed = 32,
 }
\ No newline at end of file
@@ -6,15 +6,67 @@ use soroban_sdk::{
 
 mod storage;
 mod errors;
+mod allowance;
+mod allowlist;
+mod balance;
+mod council;
+mod demurrage;
+mod mutual_credit;
+mod rate_limit;
+mod rewards;
+mod staking;
+mod streaming;
+mod timelock;
 
 use storage::DataKey;
 use errors::TokenError as CodebitError;
+pub use allowance::AllowanceTrait;
+pub use allowlist::{AllowlistConfig, AllowlistTrait};
+pub use council::{Council, CouncilAction, CouncilTrait, Proposal};
+pub use demurrage::{DemurrageConfig, DemurrageTrait};
+pub use mutual_credit::MutualCreditTrait;
+pub use rate_limit::{RateLimit, RateLimitTrait, SelfRateLimit};
+pub use rewards::RewardsTrait;
+pub use staking::{Stake, StakingConfig, StakingTrait, Tier};
+pub use streaming::{Stream, StreamingTrait};
+pub use timelock::{QueuedOperation, TimelockAction, TimelockConfig, TimelockTrait};
 
 /// Constantes del sistema DevPoints
 const MAX_NAME_LENGTH: u32 = 100;
 const MAX_SYMBOL_LENGTH: u32 = 32;
 // const MAX_DECIMALS: u32 = 0;  // Nunca se usaba en el código
 
+/// Verifica que el admin de DevPoints autorizó la operación
+/// Con el consejo multisig activo solo vale dentro de execute_proposal()
+fn require_admin(env: &Env) -> Result<Address, CodebitError> {
+    let admin: Address = env.storage().instance()
+        .get(&DataKey::Admin)
+        .ok_or(CodebitError::NotInitialized)?;
+    if council::is_enabled(env) {
+        if !council::is_executing(env) {
+            return Err(CodebitError::CouncilRequired);
+        }
+        return Ok(admin);
+    }
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Igual que require_admin() para cambios de configuración sensibles
+/// Con el timelock activo solo vale dentro de execute_admin_action()
+fn require_config_admin(env: &Env) -> Result<Address, CodebitError> {
+    if timelock::is_enabled(env) {
+        if !timelock::is_executing(env) {
+            return Err(CodebitError::TimelockRequired);
+        }
+        // La autorización se verificó al encolar la operación
+        return env.storage().instance()
+            .get(&DataKey::Admin)
+            .ok_or(CodebitError::NotInitialized);
+    }
+    require_admin(env)
+}
+
 
 /// Trait que define la interfaz del token CODEBIT según CAP-46
 pub trait CodebitTrait {
@@ -70,6 +122,9 @@ pub trait CodebitTrait {
     fn decimals(env: Env) -> u32;
     fn total_supply(env: Env) -> i128;
     fn admin(env: Env) -> Address;
+
+    /// Transfiere el rol de admin (sujeto al timelock si está activo)
+    fn set_admin(env: Env, new_admin: Address) -> Result<(), CodebitError>;
 }
 
 #[contract]
@@ -95,11 +150,11 @@ impl CodebitTrait for TokenCodebit {
         }
         
         // 3. Validar metadatos
-        if name.len() == 0 || name.len() > MAX_NAME_LENGTH {
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
             return Err(CodebitError::InvalidMetadata);
         }
         
-        if symbol.len() == 0 || symbol.len() > MAX_SYMBOL_LENGTH {
+        if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LENGTH {
             return Err(CodebitError::InvalidMetadata);
         }
         
@@ -123,34 +178,26 @@ impl CodebitTrait for TokenCodebit {
             return Err(CodebitError::NotInitialized);
         }
         
-        // 2. Solo el admin puede mintear
-        let admin: Address = env.storage().instance()
-            .get(&DataKey::Admin)
-            .ok_or(CodebitError::NotInitialized)?;
-        admin.require_auth();
+        // 2. Solo el admin (o el consejo multisig) puede mintear
+        require_admin(&env)?;
         
         // 3. Validaciones: CODEBIT debe ser > 0
         if amount <= 0 {
             return Err(CodebitError::InvalidAmount);
         }
         
-        // 4. Obtener balance actual y verificar overflow
-        let balance = Self::balance(env.clone(), to.clone());
-        let new_balance = balance.checked_add(amount)
-            .ok_or(CodebitError::OverflowError)?;
+        // En crédito mutuo los minutos nacen del crédito, no del admin
+        if mutual_credit::is_enabled(&env) {
+            return Err(CodebitError::MintDisabled);
+        }
         
-        // 5. Actualizar balance con TTL extendido
-        env.storage().persistent().set(
-            &DataKey::Balance(to.clone()), 
-            &new_balance
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::Balance(to.clone()),
-            100_000,
-            200_000
-        );
+        // En modo allowlist solo se acredita a miembros
+        allowlist::check(&env, None, &to)?;
         
-        // 6. Actualizar total supply de CODEBIT
+        // 4. Acreditar balance (con protección de overflow y TTL extendido)
+        balance::receive_balance(&env, &to, amount)?;
+        
+        // 5. Actualizar total supply de CODEBIT
         let total: i128 = env.storage().instance()
             .get(&DataKey::TotalSupply)
             .unwrap_or(0);
@@ -178,26 +225,10 @@ impl CodebitTrait for TokenCodebit {
             return Err(CodebitError::InvalidAmount);
         }
         
-        let balance = Self::balance(env.clone(), from.clone());
-        if balance < amount {
-            return Err(CodebitError::InsufficientBalance);
-        }
+        rate_limit::record_spend(&env, &from, amount)?;
         
-        // 4. Actualizar balance
-        let new_balance = balance - amount;
-        if new_balance == 0 {
-            env.storage().persistent().remove(&DataKey::Balance(from.clone()));
-        } else {
-            env.storage().persistent().set(
-                &DataKey::Balance(from.clone()),
-                &new_balance
-            );
-            env.storage().persistent().extend_ttl(
-                &DataKey::Balance(from.clone()),
-                100_000,
-                200_000
-            );
-        }
+        // 4. Debitar balance (falla si no alcanza)
+        balance::spend_balance(&env, &from, amount)?;
         
         // 5. Reducir total supply
         let total: i128 = env.storage().instance()
@@ -214,9 +245,7 @@ impl CodebitTrait for TokenCodebit {
     }
     
     fn balance(env: Env, account: Address) -> i128 {
-        env.storage().persistent()
-            .get(&DataKey::Balance(account))
-            .unwrap_or(0)
+        balance::read_balance(&env, &account)
     }
     
     fn transfer(
@@ -242,41 +271,12 @@ impl CodebitTrait for TokenCodebit {
             return Err(CodebitError::InvalidRecipient);
         }
         
-        let from_balance = Self::balance(env.clone(), from.clone());
-        if from_balance < amount {
-            return Err(CodebitError::InsufficientBalance);
-        }
-        
-        // 4. Calcular nuevos balances con protección de overflow
-        let new_from_balance = from_balance - amount;
-        let to_balance = Self::balance(env.clone(), to.clone());
-        let new_to_balance = to_balance.checked_add(amount)
-            .ok_or(CodebitError::OverflowError)?;
-        
-        // 5. Actualizar balances atómicamente
-        if new_from_balance == 0 {
-            env.storage().persistent().remove(&DataKey::Balance(from.clone()));
-        } else {
-            env.storage().persistent().set(
-                &DataKey::Balance(from.clone()),
-                &new_from_balance
-            );
-            env.storage().persistent().extend_ttl(
-                &DataKey::Balance(from.clone()),
-                100_000,
-                200_000
-            );
-        }
+        allowlist::check(&env, Some(&from), &to)?;
+        rate_limit::record_spend(&env, &from, amount)?;
         
-        env.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &new_to_balance
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::Balance(to.clone()),
-            100_000,
-            200_000
-        );
+        // 4. Actualizar balances atómicamente (falla si no alcanza)
+        balance::spend_balance_with_credit(&env, &from, amount)?;
+        balance::receive_balance(&env, &to, amount)?;
         
         Ok(())
     }
@@ -300,30 +300,12 @@ impl CodebitTrait for TokenCodebit {
             return Err(CodebitError::InvalidAmount);
         }
         
-        // 4. Actualizar allowance
-        if amount == 0 {
-            env.storage().persistent().remove(
-                &DataKey::Allowance(from.clone(), spender.clone())
-            );
-        } else {
-            env.storage().persistent().set(
-                &DataKey::Allowance(from.clone(), spender.clone()),
-                &amount
-            );
-            env.storage().persistent().extend_ttl(
-                &DataKey::Allowance(from.clone(), spender.clone()),
-                100_000,
-                200_000
-            );
-        }
-        
-        Ok(())
+        // 4. Actualizar allowance (y el índice de spenders del owner)
+        allowance::write_allowance(&env, &from, &spender, amount)
     }
     
     fn allowance(env: Env, from: Address, spender: Address) -> i128 {
-        env.storage().persistent()
-            .get(&DataKey::Allowance(from, spender))
-            .unwrap_or(0)
+        allowance::read_allowance(&env, &from, &spender)
     }
     
     fn transfer_from(
@@ -350,67 +332,22 @@ impl CodebitTrait for TokenCodebit {
             return Err(CodebitError::InvalidRecipient);
         }
         
+        allowlist::check(&env, Some(&from), &to)?;
+        
         // 4. Verificar allowance
         let allowed = Self::allowance(env.clone(), from.clone(), spender.clone());
         if allowed < amount {
             return Err(CodebitError::InsufficientAllowance);
         }
         
-        // 5. Verificar balance suficiente
-        let from_balance = Self::balance(env.clone(), from.clone());
-        if from_balance < amount {
-            return Err(CodebitError::InsufficientBalance);
-        }
+        rate_limit::record_spend(&env, &from, amount)?;
         
-        // 6. Calcular nuevos valores
-        let new_from_balance = from_balance - amount;
-        let to_balance = Self::balance(env.clone(), to.clone());
-        let new_to_balance = to_balance.checked_add(amount)
-            .ok_or(CodebitError::OverflowError)?;
-        let new_allowance = allowed - amount;
-        
-        // 7. Actualizar estado atómicamente
-        if new_from_balance == 0 {
-            env.storage().persistent().remove(&DataKey::Balance(from.clone()));
-        } else {
-            env.storage().persistent().set(
-                &DataKey::Balance(from.clone()),
-                &new_from_balance
-            );
-            env.storage().persistent().extend_ttl(
-                &DataKey::Balance(from.clone()),
-                100_000,
-                200_000
-            );
-        }
+        // 5. Mover balances (falla si no alcanza)
+        balance::spend_balance_with_credit(&env, &from, amount)?;
+        balance::receive_balance(&env, &to, amount)?;
         
-        env.storage().persistent().set(
-            &DataKey::Balance(to.clone()),
-            &new_to_balance
-        );
-        env.storage().persistent().extend_ttl(
-            &DataKey::Balance(to.clone()),
-            100_000,
-            200_000
-        );
-        
-        if new_allowance == 0 {
-            env.storage().persistent().remove(
-                &DataKey::Allowance(from.clone(), spender.clone())
-            );
-        } else {
-            env.storage().persistent().set(
-                &DataKey::Allowance(from.clone(), spender.clone()),
-                &new_allowance
-            );
-            env.storage().persistent().extend_ttl(
-                &DataKey::Allowance(from.clone(), spender.clone()),
-                100_000,
-                200_000
-            );
-        }
-        
-        Ok(())
+        // 6. Actualizar allowance
+        allowance::write_allowance(&env, &from, &spender, allowed - amount)
     }
     
     // Métodos de consulta
@@ -445,9 +382,11 @@ impl CodebitTrait for TokenCodebit {
     }
     
     fn total_supply(env: Env) -> i128 {
-        env.storage().instance()
+        let total = env.storage().instance()
             .get(&DataKey::TotalSupply)
-            .unwrap_or(0)
+            .unwrap_or(0);
+        demurrage::current_total_supply(&env, total)
+            + mutual_credit::read_outstanding_credit(&env)
     }
     
     fn admin(env: Env) -> Address {
@@ -455,6 +394,12 @@ impl CodebitTrait for TokenCodebit {
             .get(&DataKey::Admin)
             .expect("Admin not initialized")
     }
+
+    fn set_admin(env: Env, new_admin: Address) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
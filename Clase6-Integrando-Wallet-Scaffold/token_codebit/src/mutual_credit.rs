@@ -0,0 +1,106 @@
+// src/mutual_credit.rs
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_config_admin, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Trait del modo crédito mutuo (banco de tiempo de suma cero)
+///
+/// Con el modo activo un newcomer puede recibir ayuda antes de haber
+/// ganado minutos: su balance baja de 0 hasta su límite de crédito.
+/// `mint` queda deshabilitado y `total_supply` pasa a ser el supply
+/// minteado más el crédito emitido (suma de saldos negativos), que es
+/// igual a la suma de todos los balances positivos.
+pub trait MutualCreditTrait {
+    /// Activa o desactiva el modo (solo admin, incompatible con demurrage)
+    fn set_mutual_credit(env: Env, enabled: bool) -> Result<(), CodebitError>;
+
+    /// Asigna el límite de crédito de una cuenta (solo admin)
+    fn set_credit_limit(env: Env, account: Address, limit: i128) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn is_mutual_credit(env: Env) -> bool;
+    fn credit_limit(env: Env, account: Address) -> i128;
+    fn outstanding_credit(env: Env) -> i128;
+}
+
+#[contractimpl]
+impl MutualCreditTrait for TokenCodebit {
+    fn set_mutual_credit(env: Env, enabled: bool) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+
+        // Balances negativos no tienen sentido con decaimiento
+        if enabled && env.storage().instance().has(&DataKey::Demurrage) {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        if enabled {
+            env.storage().instance().set(&DataKey::MutualCredit, &true);
+        } else {
+            env.storage().instance().remove(&DataKey::MutualCredit);
+        }
+
+        Ok(())
+    }
+
+    fn set_credit_limit(env: Env, account: Address, limit: i128) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+
+        if limit < 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        // Bajar el límite no cancela la deuda existente, solo impide crecerla
+        let key = DataKey::CreditLimit(account);
+        if limit == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &limit);
+            env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+        }
+
+        Ok(())
+    }
+
+    fn is_mutual_credit(env: Env) -> bool {
+        is_enabled(&env)
+    }
+
+    fn credit_limit(env: Env, account: Address) -> i128 {
+        read_credit_limit(&env, &account)
+    }
+
+    fn outstanding_credit(env: Env) -> i128 {
+        read_outstanding_credit(&env)
+    }
+}
+
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::MutualCredit)
+}
+
+pub fn read_credit_limit(env: &Env, account: &Address) -> i128 {
+    env.storage().persistent()
+        .get(&DataKey::CreditLimit(account.clone()))
+        .unwrap_or(0)
+}
+
+pub fn read_outstanding_credit(env: &Env) -> i128 {
+    env.storage().instance()
+        .get(&DataKey::OutstandingCredit)
+        .unwrap_or(0)
+}
+
+/// Hook de balance: actualiza el crédito emitido cuando una cuenta
+/// entra, sale o se mueve dentro de saldo negativo
+pub fn on_balance_change(env: &Env, old_balance: i128, new_balance: i128) {
+    let old_debt = (-old_balance).max(0);
+    let new_debt = (-new_balance).max(0);
+    if old_debt == new_debt {
+        return;
+    }
+
+    let outstanding = read_outstanding_credit(env) + new_debt - old_debt;
+    env.storage().instance().set(&DataKey::OutstandingCredit, &outstanding);
+}
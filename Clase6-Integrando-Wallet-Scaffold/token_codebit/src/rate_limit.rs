@@ -0,0 +1,229 @@
+// src/rate_limit.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_config_admin, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Ledgers que tarda en aplicarse un límite propio más laxo (~1 día)
+/// Así una key comprometida no puede quitar el límite y vaciar la cuenta
+pub const SELF_LIMIT_DELAY_LEDGERS: u32 = 17_280;
+
+/// Tramos en que se agrupan los envíos de una ventana (acota el estado por cuenta)
+pub const WINDOW_BUCKETS: u32 = 10;
+
+/// Máximo de CODEBIT enviados por ventana de ledgers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    pub max_amount: i128,
+    pub window_ledgers: u32,
+}
+
+/// Límite que una cuenta se impone a sí misma
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelfRateLimit {
+    pub limit: RateLimit,
+    /// Límite más laxo esperando su ledger de activación
+    /// (`max_amount == 0` quita el límite propio)
+    pub pending: RateLimit,
+    /// Ledger de activación de `pending` (0 = no hay cambio pendiente)
+    pub pending_ledger: u32,
+}
+
+/// Lo enviado en un tramo de ledgers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendBucket {
+    /// Ledger del último envío del tramo
+    pub ledger: u32,
+    pub spent: i128,
+}
+
+/// Envíos recientes de una cuenta: unos para el límite del admin y otros para el propio
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendWindows {
+    pub default: Vec<SpendBucket>,
+    pub own: Vec<SpendBucket>,
+}
+
+/// Trait de límites de velocidad por cuenta
+///
+/// Cada envío (`transfer`, `transfer_from`, `burn`) cuenta contra el
+/// límite por defecto del admin y contra el límite propio de la cuenta.
+/// La ventana es móvil: en ningún tramo de `window_ledgers` ledgers
+/// seguidos se puede enviar más de `max_amount`.
+pub trait RateLimitTrait {
+    /// El admin define el límite por defecto (`max_amount == 0` lo quita)
+    fn set_default_rate_limit(env: Env, max_amount: i128, window_ledgers: u32) -> Result<(), CodebitError>;
+
+    /// La cuenta define su propio límite
+    ///
+    /// Un límite más estricto aplica de inmediato; uno más laxo (o quitarlo
+    /// con `max_amount == 0`) recién después de `SELF_LIMIT_DELAY_LEDGERS`.
+    fn set_self_rate_limit(
+        env: Env,
+        account: Address,
+        max_amount: i128,
+        window_ledgers: u32
+    ) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn default_rate_limit(env: Env) -> Option<RateLimit>;
+    fn self_rate_limit(env: Env, account: Address) -> Option<SelfRateLimit>;
+}
+
+#[contractimpl]
+impl RateLimitTrait for TokenCodebit {
+    fn set_default_rate_limit(env: Env, max_amount: i128, window_ledgers: u32) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+
+        if max_amount == 0 {
+            env.storage().instance().remove(&DataKey::DefaultRateLimit);
+            return Ok(());
+        }
+
+        let limit = validate(max_amount, window_ledgers)?;
+        env.storage().instance().set(&DataKey::DefaultRateLimit, &limit);
+
+        Ok(())
+    }
+
+    fn set_self_rate_limit(
+        env: Env,
+        account: Address,
+        max_amount: i128,
+        window_ledgers: u32
+    ) -> Result<(), CodebitError> {
+        account.require_auth();
+
+        let new_limit = if max_amount == 0 {
+            no_limit()
+        } else {
+            validate(max_amount, window_ledgers)?
+        };
+
+        let current = read_self_limit(&env, &account);
+        let tighter = match &current {
+            None => max_amount > 0,
+            Some(current) => max_amount > 0
+                && max_amount <= current.limit.max_amount
+                && window_ledgers >= current.limit.window_ledgers,
+        };
+
+        let key = DataKey::SelfRateLimit(account);
+        if tighter {
+            // Más estricto: aplica ya y descarta cualquier aflojamiento pendiente
+            let self_limit = SelfRateLimit { limit: new_limit, pending: no_limit(), pending_ledger: 0 };
+            env.storage().persistent().set(&key, &self_limit);
+            env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+        } else if let Some(mut self_limit) = current {
+            self_limit.pending = new_limit;
+            self_limit.pending_ledger = env.ledger().sequence().saturating_add(SELF_LIMIT_DELAY_LEDGERS);
+            env.storage().persistent().set(&key, &self_limit);
+            env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+        }
+
+        Ok(())
+    }
+
+    fn default_rate_limit(env: Env) -> Option<RateLimit> {
+        env.storage().instance().get(&DataKey::DefaultRateLimit)
+    }
+
+    fn self_rate_limit(env: Env, account: Address) -> Option<SelfRateLimit> {
+        read_self_limit(&env, &account)
+    }
+}
+
+/// Hook de transfer/transfer_from/burn: registra el envío y falla con
+/// RateLimitExceeded si supera alguno de los límites de la cuenta
+pub fn record_spend(env: &Env, account: &Address, amount: i128) -> Result<(), CodebitError> {
+    let default_limit: Option<RateLimit> = env.storage().instance().get(&DataKey::DefaultRateLimit);
+    let own_limit = read_self_limit(env, account).map(|self_limit| self_limit.limit);
+    if default_limit.is_none() && own_limit.is_none() {
+        return Ok(());
+    }
+
+    let key = DataKey::SpendWindows(account.clone());
+    let mut windows: SpendWindows = env.storage().persistent().get(&key)
+        .unwrap_or(SpendWindows { default: Vec::new(env), own: Vec::new(env) });
+
+    if let Some(limit) = default_limit {
+        consume(env, &mut windows.default, &limit, amount)?;
+    }
+    if let Some(limit) = own_limit {
+        consume(env, &mut windows.own, &limit, amount)?;
+    }
+
+    env.storage().persistent().set(&key, &windows);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+
+    Ok(())
+}
+
+/// Suma lo enviado en los últimos `window_ledgers` ledgers más `amount`
+///
+/// Un tramo cuenta entero mientras su último envío siga dentro de la
+/// ventana, así la suma nunca subestima lo enviado (a lo sumo sobrestima
+/// un tramo de `window_ledgers / WINDOW_BUCKETS` ledgers).
+fn consume(env: &Env, buckets: &mut Vec<SpendBucket>, limit: &RateLimit, amount: i128) -> Result<(), CodebitError> {
+    let now = env.ledger().sequence();
+    let bucket_ledgers = limit.window_ledgers.div_ceil(WINDOW_BUCKETS);
+
+    let mut recent = Vec::new(env);
+    let mut spent = amount;
+    for bucket in buckets.iter() {
+        if bucket.ledger.saturating_add(limit.window_ledgers) > now {
+            spent = spent.checked_add(bucket.spent)
+                .ok_or(CodebitError::OverflowError)?;
+            recent.push_back(bucket);
+        }
+    }
+    if spent > limit.max_amount {
+        return Err(CodebitError::RateLimitExceeded);
+    }
+
+    // Los envíos del mismo tramo se acumulan en un solo bucket
+    match recent.last() {
+        Some(last) if last.ledger / bucket_ledgers == now / bucket_ledgers => {
+            let spent = last.spent.checked_add(amount)
+                .ok_or(CodebitError::OverflowError)?;
+            recent.set(recent.len() - 1, SpendBucket { ledger: now, spent });
+        }
+        _ => recent.push_back(SpendBucket { ledger: now, spent: amount }),
+    }
+
+    *buckets = recent;
+    Ok(())
+}
+
+/// Lee el límite propio aplicando el aflojamiento pendiente si ya venció
+fn read_self_limit(env: &Env, account: &Address) -> Option<SelfRateLimit> {
+    let key = DataKey::SelfRateLimit(account.clone());
+    let mut self_limit: SelfRateLimit = env.storage().persistent().get(&key)?;
+
+    if self_limit.pending_ledger != 0 && env.ledger().sequence() >= self_limit.pending_ledger {
+        if self_limit.pending.max_amount == 0 {
+            return None;
+        }
+        self_limit.limit = self_limit.pending;
+        self_limit.pending = no_limit();
+        self_limit.pending_ledger = 0;
+    }
+
+    Some(self_limit)
+}
+
+fn no_limit() -> RateLimit {
+    RateLimit { max_amount: 0, window_ledgers: 0 }
+}
+
+fn validate(max_amount: i128, window_ledgers: u32) -> Result<RateLimit, CodebitError> {
+    if max_amount <= 0 || window_ledgers == 0 {
+        return Err(CodebitError::InvalidConfig);
+    }
+    Ok(RateLimit { max_amount, window_ledgers })
+}
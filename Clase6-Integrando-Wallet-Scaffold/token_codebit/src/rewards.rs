@@ -0,0 +1,201 @@
+// src/rewards.rs
+use soroban_sdk::{contractimpl, token, Address, Env, Vec};
+
+use crate::balance::read_balance;
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_config_admin, CodebitTrait, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Máximo de tokens de recompensa distintos
+/// Cada cambio de balance recorre la lista, así que se mantiene acotada
+const MAX_REWARD_TOKENS: u32 = 5;
+
+/// Escala de punto fijo del acumulador reward-per-token
+const SCALE: i128 = 1_000_000_000_000;
+
+/// Trait de recompensas pro-rata para holders de CODEBIT
+///
+/// Los sponsors donan un pool de otro token SEP-41 (ej: USDC vía Stellar
+/// Asset Contract) y cada holder puede reclamar su parte proporcional al
+/// balance que tenía en el momento de la donación, sin iterar holders.
+/// Solo se aceptan tokens habilitados por el admin: cada uno ocupa un
+/// lugar de MAX_REWARD_TOKENS y encarece todos los cambios de balance.
+pub trait RewardsTrait {
+    /// El admin habilita un token de recompensa (sensible: pasa por el timelock)
+    fn add_reward_token(env: Env, reward_token: Address) -> Result<(), CodebitError>;
+
+    /// Un sponsor deposita `amount` de `reward_token` para repartir
+    fn fund_rewards(
+        env: Env,
+        sponsor: Address,
+        reward_token: Address,
+        amount: i128
+    ) -> Result<(), CodebitError>;
+
+    /// Transfiere al holder todas sus recompensas acumuladas
+    fn claim_rewards(env: Env, account: Address, reward_token: Address) -> Result<i128, CodebitError>;
+
+    // Métodos de consulta
+    fn pending_rewards(env: Env, account: Address, reward_token: Address) -> i128;
+    fn reward_tokens(env: Env) -> Vec<Address>;
+}
+
+#[contractimpl]
+impl RewardsTrait for TokenCodebit {
+    fn add_reward_token(env: Env, reward_token: Address) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+
+        // Un contrato no puede llamarse a sí mismo
+        if reward_token == env.current_contract_address() {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        let mut tokens = read_reward_tokens(&env);
+        if tokens.contains(&reward_token) {
+            return Ok(());
+        }
+        if tokens.len() >= MAX_REWARD_TOKENS {
+            return Err(CodebitError::RewardTokenLimit);
+        }
+        tokens.push_back(reward_token);
+        env.storage().instance().set(&DataKey::RewardTokens, &tokens);
+
+        Ok(())
+    }
+
+    fn fund_rewards(
+        env: Env,
+        sponsor: Address,
+        reward_token: Address,
+        amount: i128
+    ) -> Result<(), CodebitError> {
+        // 1. Verificar inicialización
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(CodebitError::NotInitialized);
+        }
+
+        // 2. El sponsor autoriza la donación
+        sponsor.require_auth();
+
+        // 3. Validaciones
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        // El acumulador asume balances constantes entre escrituras
+        if env.storage().instance().has(&DataKey::Demurrage) {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        // Cualquiera puede donar, pero solo en tokens habilitados
+        if !read_reward_tokens(&env).contains(&reward_token) {
+            return Err(CodebitError::RewardTokenNotListed);
+        }
+
+        let eligible = eligible_supply(&env);
+        if eligible <= 0 {
+            return Err(CodebitError::NoEligibleHolders);
+        }
+
+        // 4. Recibir el pool en el contrato
+        token::TokenClient::new(&env, &reward_token)
+            .transfer(&sponsor, env.current_contract_address(), &amount);
+
+        // 5. Actualizar el acumulador global
+        let increment = amount.checked_mul(SCALE)
+            .ok_or(CodebitError::OverflowError)?
+            / eligible;
+        let per_token = read_reward_per_token(&env, &reward_token)
+            .checked_add(increment)
+            .ok_or(CodebitError::OverflowError)?;
+        env.storage().instance().set(&DataKey::RewardPerToken(reward_token), &per_token);
+
+        Ok(())
+    }
+
+    fn claim_rewards(env: Env, account: Address, reward_token: Address) -> Result<i128, CodebitError> {
+        // 1. Solo el holder reclama sus recompensas
+        account.require_auth();
+
+        // 2. Llevar la cuenta al acumulador actual
+        checkpoint(&env, &account, read_balance(&env, &account));
+
+        let key = DataKey::AccruedRewards(account.clone(), reward_token.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        // 3. Pagar desde el pool del contrato
+        env.storage().persistent().remove(&key);
+        token::TokenClient::new(&env, &reward_token)
+            .transfer(&env.current_contract_address(), &account, &amount);
+
+        Ok(amount)
+    }
+
+    fn pending_rewards(env: Env, account: Address, reward_token: Address) -> i128 {
+        let accrued: i128 = env.storage().persistent()
+            .get(&DataKey::AccruedRewards(account.clone(), reward_token.clone()))
+            .unwrap_or(0);
+        let balance = read_balance(&env, &account);
+        accrued + earned(&env, &account, &reward_token, balance)
+    }
+
+    fn reward_tokens(env: Env) -> Vec<Address> {
+        read_reward_tokens(&env)
+    }
+}
+
+/// Hook de balance: acumula las recompensas ganadas con el balance
+/// anterior antes de que cambie (se llama antes de cada escritura)
+pub fn checkpoint(env: &Env, account: &Address, old_balance: i128) {
+    // La custodia del contrato no participa del reparto
+    if *account == env.current_contract_address() {
+        return;
+    }
+
+    for reward_token in read_reward_tokens(env).iter() {
+        let pending = earned(env, account, &reward_token, old_balance);
+        if pending > 0 {
+            let key = DataKey::AccruedRewards(account.clone(), reward_token.clone());
+            let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(accrued + pending));
+            env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+        }
+
+        let per_token = read_reward_per_token(env, &reward_token);
+        let key = DataKey::RewardIndex(account.clone(), reward_token);
+        env.storage().persistent().set(&key, &per_token);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    }
+}
+
+/// Recompensas ganadas desde el último checkpoint de la cuenta
+fn earned(env: &Env, account: &Address, reward_token: &Address, balance: i128) -> i128 {
+    if balance <= 0 || *account == env.current_contract_address() {
+        return 0;
+    }
+    let per_token = read_reward_per_token(env, reward_token);
+    let index: i128 = env.storage().persistent()
+        .get(&DataKey::RewardIndex(account.clone(), reward_token.clone()))
+        .unwrap_or(0);
+    balance * (per_token - index) / SCALE
+}
+
+/// Supply que participa del reparto (sin la custodia del contrato)
+fn eligible_supply(env: &Env) -> i128 {
+    TokenCodebit::total_supply(env.clone()) - read_balance(env, &env.current_contract_address())
+}
+
+fn read_reward_per_token(env: &Env, reward_token: &Address) -> i128 {
+    env.storage().instance()
+        .get(&DataKey::RewardPerToken(reward_token.clone()))
+        .unwrap_or(0)
+}
+
+fn read_reward_tokens(env: &Env) -> Vec<Address> {
+    env.storage().instance()
+        .get(&DataKey::RewardTokens)
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,225 @@
+// src/staking.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+use crate::balance::{receive_balance, spend_balance};
+use crate::errors::TokenError as CodebitError;
+use crate::storage::DataKey;
+use crate::{require_admin, require_config_admin, TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Tiers de la comunidad según CODEBIT en stake
+/// Ordenados: otros contratos pueden comparar `tier_of(x) >= Tier::Mentor`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Tier {
+    None = 0,
+    Reviewer = 1,
+    Mentor = 2,
+    Maintainer = 3,
+}
+
+/// Umbrales de cada tier y cooldown de unstake
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingConfig {
+    pub reviewer: i128,
+    pub mentor: i128,
+    pub maintainer: i128,
+    /// Ledgers entre request_unstake() y withdraw()
+    pub cooldown_ledgers: u32,
+}
+
+/// Stake de una cuenta
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stake {
+    /// CODEBIT bloqueados que cuentan para el tier
+    pub amount: i128,
+    /// CODEBIT en cooldown (ya no cuentan para el tier)
+    pub unstaking: i128,
+    /// Ledger desde el que se puede retirar lo que está en cooldown
+    pub unlock_ledger: u32,
+}
+
+/// Trait de staking de CODEBIT para tiers de reputación
+///
+/// Los CODEBIT en stake quedan custodiados por el contrato. Para
+/// recuperarlos hay que pedir el unstake y esperar `cooldown_ledgers`,
+/// así nadie sube de tier justo antes de una votación y se va después.
+pub trait StakingTrait {
+    /// El admin define umbrales de tiers y cooldown
+    fn set_staking_config(env: Env, config: StakingConfig) -> Result<(), CodebitError>;
+
+    /// Bloquea CODEBIT de la cuenta
+    fn stake(env: Env, account: Address, amount: i128) -> Result<(), CodebitError>;
+
+    /// Mueve CODEBIT del stake al cooldown (reinicia el cooldown)
+    fn request_unstake(env: Env, account: Address, amount: i128) -> Result<(), CodebitError>;
+
+    /// Devuelve a la cuenta lo que ya cumplió el cooldown
+    fn withdraw(env: Env, account: Address) -> Result<i128, CodebitError>;
+
+    /// El admin quema CODEBIT en stake por mala conducta
+    /// (primero del stake activo, después del cooldown)
+    fn slash(env: Env, account: Address, amount: i128) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn staking_config(env: Env) -> Option<StakingConfig>;
+    fn stake_of(env: Env, account: Address) -> Stake;
+    fn tier_of(env: Env, account: Address) -> Tier;
+}
+
+#[contractimpl]
+impl StakingTrait for TokenCodebit {
+    fn set_staking_config(env: Env, config: StakingConfig) -> Result<(), CodebitError> {
+        require_config_admin(&env)?;
+
+        if config.reviewer <= 0 || config.reviewer > config.mentor || config.mentor > config.maintainer {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        env.storage().instance().set(&DataKey::StakingConfig, &config);
+
+        Ok(())
+    }
+
+    fn stake(env: Env, account: Address, amount: i128) -> Result<(), CodebitError> {
+        // 1. Staking habilitado por el admin
+        read_config(&env).ok_or(CodebitError::ModeDisabled)?;
+
+        // 2. La cuenta autoriza el bloqueo
+        account.require_auth();
+
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        // 3. Mover a la custodia del contrato
+        spend_balance(&env, &account, amount)?;
+        receive_balance(&env, &env.current_contract_address(), amount)?;
+
+        let mut stake = read_stake(&env, &account);
+        stake.amount = stake.amount.checked_add(amount)
+            .ok_or(CodebitError::OverflowError)?;
+        write_stake(&env, &account, &stake);
+
+        Ok(())
+    }
+
+    fn request_unstake(env: Env, account: Address, amount: i128) -> Result<(), CodebitError> {
+        let config = read_config(&env).ok_or(CodebitError::ModeDisabled)?;
+        account.require_auth();
+
+        let mut stake = read_stake(&env, &account);
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        if amount > stake.amount {
+            return Err(CodebitError::InsufficientBalance);
+        }
+
+        stake.amount -= amount;
+        stake.unstaking += amount;
+        stake.unlock_ledger = env.ledger().sequence().saturating_add(config.cooldown_ledgers);
+        write_stake(&env, &account, &stake);
+
+        Ok(())
+    }
+
+    fn withdraw(env: Env, account: Address) -> Result<i128, CodebitError> {
+        account.require_auth();
+
+        let mut stake = read_stake(&env, &account);
+        if stake.unstaking <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        if env.ledger().sequence() < stake.unlock_ledger {
+            return Err(CodebitError::CooldownActive);
+        }
+
+        // Pagar desde la custodia del contrato
+        let amount = stake.unstaking;
+        spend_balance(&env, &env.current_contract_address(), amount)?;
+        receive_balance(&env, &account, amount)?;
+
+        stake.unstaking = 0;
+        write_stake(&env, &account, &stake);
+
+        Ok(amount)
+    }
+
+    fn slash(env: Env, account: Address, amount: i128) -> Result<(), CodebitError> {
+        require_admin(&env)?;
+
+        let mut stake = read_stake(&env, &account);
+        let slashable = stake.amount.checked_add(stake.unstaking)
+            .ok_or(CodebitError::OverflowError)?;
+        if amount <= 0 || amount > slashable {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        let from_active = amount.min(stake.amount);
+        stake.amount -= from_active;
+        stake.unstaking -= amount - from_active;
+        write_stake(&env, &account, &stake);
+
+        // Quemar desde la custodia: sale del total supply
+        spend_balance(&env, &env.current_contract_address(), amount)?;
+        let total: i128 = env.storage().instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0);
+        let new_total = total.checked_sub(amount)
+            .ok_or(CodebitError::OverflowError)?;
+        env.storage().instance().set(&DataKey::TotalSupply, &new_total);
+
+        Ok(())
+    }
+
+    fn staking_config(env: Env) -> Option<StakingConfig> {
+        read_config(&env)
+    }
+
+    fn stake_of(env: Env, account: Address) -> Stake {
+        read_stake(&env, &account)
+    }
+
+    fn tier_of(env: Env, account: Address) -> Tier {
+        let Some(config) = read_config(&env) else {
+            return Tier::None;
+        };
+
+        let staked = read_stake(&env, &account).amount;
+        if staked >= config.maintainer {
+            Tier::Maintainer
+        } else if staked >= config.mentor {
+            Tier::Mentor
+        } else if staked >= config.reviewer {
+            Tier::Reviewer
+        } else {
+            Tier::None
+        }
+    }
+}
+
+fn read_config(env: &Env) -> Option<StakingConfig> {
+    env.storage().instance().get(&DataKey::StakingConfig)
+}
+
+fn read_stake(env: &Env, account: &Address) -> Stake {
+    env.storage().persistent()
+        .get(&DataKey::Stake(account.clone()))
+        .unwrap_or_default()
+}
+
+/// Stake vacío => se elimina la entrada
+fn write_stake(env: &Env, account: &Address, stake: &Stake) {
+    let key = DataKey::Stake(account.clone());
+    if stake.amount == 0 && stake.unstaking == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, stake);
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+    }
+}
@@ -46,6 +46,114 @@ pub enum DataKey {
     /// Flag para verificar inicialización - Instance Storage
     /// Previene re-inicialización del contrato
     Initialized,
+
+    /// Stream de pago continuo - Persistent Storage
+    /// Mentorías pagadas por minuto (id => Stream)
+    Stream(u64),
+
+    /// Contador de streams creados - Instance Storage
+    /// Se usa para asignar el id del siguiente stream
+    StreamCount,
+
+    /// Configuración de demurrage - Instance Storage
+    /// Solo existe si se inicializó con initialize_with_demurrage()
+    Demurrage,
+
+    /// Supply sujeto a demurrage - Instance Storage
+    /// Suma de balances no exentos y último ledger liquidado
+    DecayingSupply,
+
+    /// Último ledger en que se tocó un balance - Persistent Storage
+    /// Base para calcular el decaimiento pendiente
+    LastTouched(Address),
+
+    /// Cuenta exenta de demurrage - Persistent Storage
+    /// Ej: tesorería de la comunidad
+    DemurrageExempt(Address),
+
+    /// Flag del modo crédito mutuo - Instance Storage
+    /// Balances pueden ser negativos hasta el límite de cada cuenta
+    MutualCredit,
+
+    /// Límite de crédito de una cuenta - Persistent Storage
+    /// Cuánto puede bajar de 0 (ej: 60 = una hora de ayuda)
+    CreditLimit(Address),
+
+    /// Crédito emitido - Instance Storage
+    /// Suma de todos los saldos negativos (en valor absoluto)
+    OutstandingCredit,
+
+    /// Tokens de recompensa donados por sponsors - Instance Storage
+    /// Lista acotada (ej: USDC, XLM)
+    RewardTokens,
+
+    /// Acumulador reward-per-token - Instance Storage
+    /// Recompensa acumulada por cada CODEBIT (punto fijo)
+    RewardPerToken(Address),
+
+    /// Valor del acumulador en el último checkpoint - Persistent Storage
+    /// Tupla (holder, reward_token)
+    RewardIndex(Address, Address),
+
+    /// Recompensas acumuladas sin reclamar - Persistent Storage
+    /// Tupla (holder, reward_token)
+    AccruedRewards(Address, Address),
+
+    /// Consejo multisig (firmantes + threshold) - Instance Storage
+    /// Si existe, reemplaza la firma del admin único
+    Council,
+
+    /// Propuesta del consejo - Persistent Storage
+    Proposal(u64),
+
+    /// Contador de propuestas creadas - Instance Storage
+    ProposalCount,
+
+    /// Flag de ejecución de propuesta - Temporary Storage
+    /// Solo existe durante execute_proposal()
+    CouncilExecuting,
+
+    /// Configuración del timelock (delay + gracia) - Instance Storage
+    /// Si existe, los cambios sensibles deben encolarse
+    Timelock,
+
+    /// Operación encolada en el timelock - Persistent Storage
+    Queued(u64),
+
+    /// Contador de operaciones encoladas - Instance Storage
+    TimelockCount,
+
+    /// Flag de ejecución del timelock - Temporary Storage
+    /// Solo existe durante execute_admin_action()
+    TimelockExecuting,
+
+    /// Umbrales de tiers y cooldown de staking - Instance Storage
+    StakingConfig,
+
+    /// Stake de una cuenta - Persistent Storage
+    Stake(Address),
+
+    /// Configuración del modo allowlist - Instance Storage
+    /// Si existe, solo los miembros reciben CODEBIT
+    Allowlist,
+
+    /// Miembro verificado de la cohorte - Persistent Storage
+    Allowlisted(Address),
+
+    /// Cuenta que puede agregar/quitar miembros - Persistent Storage
+    Registrar(Address),
+
+    /// Límite de velocidad por defecto - Instance Storage
+    DefaultRateLimit,
+
+    /// Límite de velocidad propio de una cuenta - Persistent Storage
+    SelfRateLimit(Address),
+
+    /// Ventanas de envío de una cuenta - Persistent Storage
+    SpendWindows(Address),
+
+    /// Spenders con allowance activa de un owner - Persistent Storage
+    Spenders(Address),
 }
 
 /// Metadata struct para almacenar información del token
@@ -0,0 +1,220 @@
+// src/streaming.rs
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+use crate::allowlist;
+use crate::balance::{receive_balance, spend_balance};
+use crate::errors::TokenError as CodebitError;
+use crate::rate_limit;
+use crate::storage::DataKey;
+use crate::{TokenCodebit, TokenCodebitArgs, TokenCodebitClient};
+
+/// Stream de pago continuo de CODEBIT
+///
+/// El payer deposita `rate_per_second * (stop - start)` CODEBIT que quedan
+/// custodiados por el contrato y se liberan al recipient segundo a segundo
+/// (según `env.ledger().timestamp()`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub payer: Address,
+    pub recipient: Address,
+    /// CODEBIT liberados por cada segundo de ledger
+    pub rate_per_second: i128,
+    /// Timestamp (segundos) en que empieza a acumularse
+    pub start: u64,
+    /// Timestamp (segundos) en que termina el stream
+    pub stop: u64,
+    /// Total depositado por el payer
+    pub deposit: i128,
+    /// CODEBIT ya retirados por el recipient
+    pub withdrawn: i128,
+}
+
+/// Trait de pagos continuos (mentorías pagadas por minuto)
+pub trait StreamingTrait {
+    /// Crea un stream depositando el total en el contrato
+    fn create_stream(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        rate_per_second: i128,
+        start: u64,
+        stop: u64
+    ) -> Result<u64, CodebitError>;
+
+    /// El recipient retira todo lo acumulado hasta ahora
+    fn withdraw_from_stream(env: Env, stream_id: u64) -> Result<i128, CodebitError>;
+
+    /// Payer o recipient cancelan el stream con reparto pro-rata
+    fn cancel_stream(env: Env, caller: Address, stream_id: u64) -> Result<(), CodebitError>;
+
+    /// Consulta un stream
+    fn get_stream(env: Env, stream_id: u64) -> Result<Stream, CodebitError>;
+
+    /// CODEBIT acumulados que el recipient puede retirar ahora
+    fn stream_balance(env: Env, stream_id: u64) -> Result<i128, CodebitError>;
+}
+
+#[contractimpl]
+impl StreamingTrait for TokenCodebit {
+    fn create_stream(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        rate_per_second: i128,
+        start: u64,
+        stop: u64
+    ) -> Result<u64, CodebitError> {
+        // 1. Verificar inicialización
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            return Err(CodebitError::NotInitialized);
+        }
+
+        // 2. El payer autoriza el depósito
+        payer.require_auth();
+
+        // 3. Validaciones
+        if rate_per_second <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        if payer == recipient || recipient == env.current_contract_address() {
+            return Err(CodebitError::InvalidRecipient);
+        }
+
+        if start >= stop || start < env.ledger().timestamp() {
+            return Err(CodebitError::InvalidSchedule);
+        }
+
+        // La custodia siempre es miembro: se valida el recipient final
+        allowlist::check(&env, Some(&payer), &recipient)?;
+
+        let deposit = rate_per_second
+            .checked_mul((stop - start) as i128)
+            .ok_or(CodebitError::OverflowError)?;
+
+        // 4. Mover el depósito del payer al contrato (custodia)
+        // El depósito cuenta como enviado para el límite de velocidad
+        rate_limit::record_spend(&env, &payer, deposit)?;
+        spend_balance(&env, &payer, deposit)?;
+        receive_balance(&env, &env.current_contract_address(), deposit)?;
+
+        // 5. Guardar el stream con un id incremental
+        let stream_id: u64 = env.storage().instance()
+            .get(&DataKey::StreamCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::StreamCount, &(stream_id + 1));
+
+        let stream = Stream {
+            payer,
+            recipient,
+            rate_per_second,
+            start,
+            stop,
+            deposit,
+            withdrawn: 0,
+        };
+        write_stream(&env, stream_id, &stream);
+
+        Ok(stream_id)
+    }
+
+    fn withdraw_from_stream(env: Env, stream_id: u64) -> Result<i128, CodebitError> {
+        let mut stream = read_stream(&env, stream_id)?;
+
+        // 1. Solo el recipient retira (si sigue en la allowlist)
+        stream.recipient.require_auth();
+        allowlist::check(&env, None, &stream.recipient)?;
+
+        // 2. Calcular lo acumulado y no retirado
+        let amount = streamed_amount(&env, &stream) - stream.withdrawn;
+        if amount <= 0 {
+            return Err(CodebitError::InvalidAmount);
+        }
+
+        // 3. Pagar desde la custodia del contrato
+        spend_balance(&env, &env.current_contract_address(), amount)?;
+        receive_balance(&env, &stream.recipient, amount)?;
+
+        // 4. Actualizar o cerrar el stream si ya se liberó todo
+        stream.withdrawn += amount;
+        if stream.withdrawn == stream.deposit {
+            env.storage().persistent().remove(&DataKey::Stream(stream_id));
+        } else {
+            write_stream(&env, stream_id, &stream);
+        }
+
+        Ok(amount)
+    }
+
+    fn cancel_stream(env: Env, caller: Address, stream_id: u64) -> Result<(), CodebitError> {
+        let mut stream = read_stream(&env, stream_id)?;
+
+        // 1. Solo payer o recipient pueden cancelar
+        caller.require_auth();
+        if caller != stream.payer && caller != stream.recipient {
+            return Err(CodebitError::Unauthorized);
+        }
+
+        // 2. Reparto pro-rata según el timestamp actual
+        let streamed = streamed_amount(&env, &stream);
+        let to_recipient = streamed - stream.withdrawn;
+        let to_payer = stream.deposit - streamed;
+
+        let contract = env.current_contract_address();
+        if to_payer > 0 {
+            spend_balance(&env, &contract, to_payer)?;
+            receive_balance(&env, &stream.payer, to_payer)?;
+        }
+
+        // 3. Si el recipient salió de la allowlist lo acumulado queda en
+        // custodia: el stream se cierra en `now` y lo retira si vuelve
+        if to_recipient > 0 && allowlist::check(&env, None, &stream.recipient).is_err() {
+            stream.stop = env.ledger().timestamp().min(stream.stop);
+            stream.deposit = streamed;
+            write_stream(&env, stream_id, &stream);
+            return Ok(());
+        }
+
+        if to_recipient > 0 {
+            spend_balance(&env, &contract, to_recipient)?;
+            receive_balance(&env, &stream.recipient, to_recipient)?;
+        }
+
+        // 4. Eliminar el stream
+        env.storage().persistent().remove(&DataKey::Stream(stream_id));
+
+        Ok(())
+    }
+
+    fn get_stream(env: Env, stream_id: u64) -> Result<Stream, CodebitError> {
+        read_stream(&env, stream_id)
+    }
+
+    fn stream_balance(env: Env, stream_id: u64) -> Result<i128, CodebitError> {
+        let stream = read_stream(&env, stream_id)?;
+        Ok(streamed_amount(&env, &stream) - stream.withdrawn)
+    }
+}
+
+/// CODEBIT liberados desde `start` hasta ahora (tope en `stop`)
+fn streamed_amount(env: &Env, stream: &Stream) -> i128 {
+    let now = env.ledger().timestamp();
+    if now <= stream.start {
+        return 0;
+    }
+    let elapsed = now.min(stream.stop) - stream.start;
+    stream.rate_per_second * elapsed as i128
+}
+
+fn read_stream(env: &Env, stream_id: u64) -> Result<Stream, CodebitError> {
+    env.storage().persistent()
+        .get(&DataKey::Stream(stream_id))
+        .ok_or(CodebitError::StreamNotFound)
+}
+
+fn write_stream(env: &Env, stream_id: u64, stream: &Stream) {
+    let key = DataKey::Stream(stream_id);
+    env.storage().persistent().set(&key, stream);
+    env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+}
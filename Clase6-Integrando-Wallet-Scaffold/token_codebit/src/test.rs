@@ -3,8 +3,8 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::Address as _,
-    Address, Env, String,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, String, Symbol,
 };
 
 // ============================================================================
@@ -488,6 +488,16 @@ fn test_operations_without_init() {
         client.try_approve(&alice, &bob, &100),
         Err(Ok(CodebitError::NotInitialized))
     );
+
+    assert_eq!(
+        client.try_increase_allowance(&alice, &bob, &100),
+        Err(Ok(CodebitError::NotInitialized))
+    );
+
+    assert_eq!(
+        client.try_decrease_allowance(&alice, &bob, &100),
+        Err(Ok(CodebitError::NotInitialized))
+    );
 }
 
 // ============================================================================
@@ -563,3 +573,983 @@ fn test_complex_transfer_sequence() {
     assert_eq!(client.balance(&charlie), 100); // 0 + 150 - 50
     assert_eq!(client.total_supply(), 1000);   // Nunca cambia
 }
+
+// ============================================================================
+// 8️⃣ TESTS DE STREAMING (PAGO POR MINUTO)
+// ============================================================================
+
+/// Helper: token inicializado con `amount` CODEBIT minteados al payer
+fn setup_stream_token(env: &Env, payer: &Address, amount: i128) -> TokenCodebitClient<'static> {
+    let contract_id = env.register(TokenCodebit, ());
+    let client = TokenCodebitClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let _ = client.try_initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    ).unwrap();
+
+    env.mock_all_auths();
+    let _ = client.try_mint(payer, &amount).unwrap();
+    client
+}
+
+/// Test: el recipient retira lo acumulado a mitad del stream
+#[test]
+fn test_stream_withdraw_accrued() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let mentee = Address::generate(&env);
+    let mentor = Address::generate(&env);
+    let client = setup_stream_token(&env, &mentee, 1000);
+
+    // 10 minutos de mentoría: 1 CODEBIT por segundo durante 600 segundos
+    let id = client.create_stream(&mentee, &mentor, &1, &1_000, &1_600);
+    assert_eq!(client.balance(&mentee), 400);
+    assert_eq!(client.balance(&client.address), 600);
+
+    env.ledger().set_timestamp(1_250);
+    assert_eq!(client.stream_balance(&id), 250);
+    assert_eq!(client.withdraw_from_stream(&id), 250);
+    assert_eq!(client.balance(&mentor), 250);
+    assert_eq!(client.stream_balance(&id), 0);
+
+    // Después de stop solo se puede retirar el resto del depósito
+    env.ledger().set_timestamp(5_000);
+    assert_eq!(client.withdraw_from_stream(&id), 350);
+    assert_eq!(client.balance(&mentor), 600);
+    assert_eq!(client.try_get_stream(&id), Err(Ok(CodebitError::StreamNotFound)));
+    assert_eq!(client.total_supply(), 1000);
+}
+
+/// Test: cancelación con reparto pro-rata
+#[test]
+fn test_stream_cancel_pro_rata() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let mentee = Address::generate(&env);
+    let mentor = Address::generate(&env);
+    let client = setup_stream_token(&env, &mentee, 1000);
+
+    let id = client.create_stream(&mentee, &mentor, &2, &200, &500);
+    assert_eq!(client.balance(&mentee), 400);
+
+    // 100 segundos * 2 CODEBIT = 200 para el mentor, 400 vuelven al mentee
+    env.ledger().set_timestamp(300);
+    client.cancel_stream(&mentor, &id);
+
+    assert_eq!(client.balance(&mentor), 200);
+    assert_eq!(client.balance(&mentee), 800);
+    assert_eq!(client.balance(&client.address), 0);
+    assert_eq!(client.try_stream_balance(&id), Err(Ok(CodebitError::StreamNotFound)));
+}
+
+/// Test: validaciones de creación y cancelación
+#[test]
+fn test_stream_invalid_operations() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let mentee = Address::generate(&env);
+    let mentor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let client = setup_stream_token(&env, &mentee, 100);
+
+    // start >= stop o en el pasado
+    let result = client.try_create_stream(&mentee, &mentor, &1, &1_100, &1_100);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidSchedule)));
+    let result = client.try_create_stream(&mentee, &mentor, &1, &900, &1_100);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidSchedule)));
+
+    // Depósito mayor al balance
+    let result = client.try_create_stream(&mentee, &mentor, &1, &1_000, &1_200);
+    assert_eq!(result, Err(Ok(CodebitError::InsufficientBalance)));
+
+    // Rate inválido y stream a sí mismo
+    let result = client.try_create_stream(&mentee, &mentor, &0, &1_000, &1_050);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidAmount)));
+    let result = client.try_create_stream(&mentee, &mentee, &1, &1_000, &1_050);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidRecipient)));
+
+    // Un tercero no puede cancelar
+    let id = client.create_stream(&mentee, &mentor, &1, &1_000, &1_050);
+    let result = client.try_cancel_stream(&stranger, &id);
+    assert_eq!(result, Err(Ok(CodebitError::Unauthorized)));
+
+    // Antes de start no hay nada que retirar
+    let result = client.try_withdraw_from_stream(&id);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidAmount)));
+}
+
+// ============================================================================
+// 9️⃣ TESTS DE DEMURRAGE (MINUTOS OCIOSOS DECAEN)
+// ============================================================================
+
+/// Helper: token con demurrage de 10% cada 100 ledgers
+fn setup_demurrage_token(env: &Env) -> TokenCodebitClient<'static> {
+    env.ledger().set_sequence_number(100);
+    let contract_id = env.register(TokenCodebit, ());
+    let client = TokenCodebitClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize_with_demurrage(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0,
+        &1_000,
+        &100
+    );
+    client
+}
+
+/// Test: el balance reportado decae y se liquida en la siguiente escritura
+#[test]
+fn test_demurrage_decays_idle_balances() {
+    let env = Env::default();
+    let client = setup_demurrage_token(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.mint(&alice, &1000);
+
+    // Dentro del mismo período no hay decaimiento
+    env.ledger().set_sequence_number(199);
+    assert_eq!(client.balance(&alice), 1000);
+
+    // Dos períodos: 1000 * 0.9^2 = 810
+    env.ledger().set_sequence_number(300);
+    assert_eq!(client.balance(&alice), 810);
+    assert_eq!(client.total_supply(), 810);
+
+    // La transferencia liquida el decaimiento de Alice
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.balance(&alice), 800);
+    assert_eq!(client.balance(&bob), 10);
+    assert_eq!(client.total_supply(), 810);
+
+    // No se puede gastar lo que ya decayó
+    let result = client.try_transfer(&alice, &bob, &801);
+    assert_eq!(result, Err(Ok(CodebitError::InsufficientBalance)));
+
+    // Un período más: 800 -> 720 y 10 -> 9
+    env.ledger().set_sequence_number(400);
+    assert_eq!(client.balance(&alice) + client.balance(&bob), 729);
+    assert_eq!(client.total_supply(), 729);
+}
+
+/// Test: cuentas exentas no decaen y el total supply sigue consistente
+#[test]
+fn test_demurrage_exemptions() {
+    let env = Env::default();
+    let client = setup_demurrage_token(&env);
+    let treasury = Address::generate(&env);
+    let alice = Address::generate(&env);
+
+    client.mint(&treasury, &500);
+    client.mint(&alice, &1000);
+    client.set_demurrage_exempt(&treasury, &true);
+    assert!(client.is_demurrage_exempt(&treasury));
+
+    env.ledger().set_sequence_number(200);
+    assert_eq!(client.balance(&treasury), 500);
+    assert_eq!(client.balance(&alice), 900);
+    assert_eq!(client.total_supply(), 1400);
+
+    // Al quitar la exención vuelve a decaer desde ahora
+    client.set_demurrage_exempt(&treasury, &false);
+    env.ledger().set_sequence_number(300);
+    assert_eq!(client.balance(&treasury), 450);
+    assert_eq!(client.balance(&alice), 810);
+    assert_eq!(client.total_supply(), 1260);
+
+    // Cambiar la tasa a 0 congela los balances ya liquidados
+    client.transfer(&alice, &treasury, &10);
+    client.set_demurrage_rate(&0);
+    env.ledger().set_sequence_number(900);
+    assert_eq!(client.balance(&alice), 800);
+    assert_eq!(client.balance(&treasury), 460);
+    assert_eq!(client.total_supply(), 1260);
+    assert_eq!(client.demurrage_config(), Some(DemurrageConfig { rate_bps: 0, period_ledgers: 100 }));
+}
+
+/// Test: configuración inválida y modo desactivado
+#[test]
+fn test_demurrage_invalid_config() {
+    let env = Env::default();
+    let contract_id = env.register(TokenCodebit, ());
+    let client = TokenCodebitClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let name = String::from_str(&env, "Codebit");
+    let symbol = String::from_str(&env, "CODE");
+    env.mock_all_auths();
+
+    let result = client.try_initialize_with_demurrage(&admin, &name, &symbol, &0, &10_001, &100);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidConfig)));
+    let result = client.try_initialize_with_demurrage(&admin, &name, &symbol, &0, &100, &0);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidConfig)));
+
+    // Sin demurrage los comandos de configuración fallan
+    client.initialize(&admin, &name, &symbol, &0);
+    assert_eq!(client.demurrage_config(), None);
+    assert_eq!(client.try_set_demurrage_rate(&100), Err(Ok(CodebitError::ModeDisabled)));
+}
+
+// ============================================================================
+// 🔟 TESTS DE CRÉDITO MUTUO (SALDOS NEGATIVOS ACOTADOS)
+// ============================================================================
+
+/// Helper: token inicializado con crédito mutuo activo
+fn setup_mutual_credit_token(env: &Env) -> TokenCodebitClient<'static> {
+    let contract_id = env.register(TokenCodebit, ());
+    let client = TokenCodebitClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    env.mock_all_auths();
+    client.initialize(
+        &admin,
+        &String::from_str(env, "Codebit"),
+        &String::from_str(env, "CODE"),
+        &0
+    );
+    client.set_mutual_credit(&true);
+    client
+}
+
+/// Test: un newcomer paga una hora de ayuda antes de ganar minutos
+#[test]
+fn test_mutual_credit_negative_balance() {
+    let env = Env::default();
+    let client = setup_mutual_credit_token(&env);
+    let newcomer = Address::generate(&env);
+    let mentor = Address::generate(&env);
+
+    client.set_credit_limit(&newcomer, &60);
+    assert_eq!(client.credit_limit(&newcomer), 60);
+
+    client.transfer(&newcomer, &mentor, &45);
+    assert_eq!(client.balance(&newcomer), -45);
+    assert_eq!(client.balance(&mentor), 45);
+
+    // Suma cero: balances suman 0, total_supply = crédito emitido
+    assert_eq!(client.outstanding_credit(), 45);
+    assert_eq!(client.total_supply(), 45);
+
+    // No puede bajar de -60
+    let result = client.try_transfer(&newcomer, &mentor, &16);
+    assert_eq!(result, Err(Ok(CodebitError::CreditLimitExceeded)));
+
+    // El mentor sin límite no puede bajar de 0
+    let result = client.try_transfer(&mentor, &newcomer, &46);
+    assert_eq!(result, Err(Ok(CodebitError::CreditLimitExceeded)));
+
+    // Cuando el newcomer ayuda de vuelta, repaga su deuda
+    client.transfer(&mentor, &newcomer, &30);
+    assert_eq!(client.balance(&newcomer), -15);
+    assert_eq!(client.outstanding_credit(), 15);
+    assert_eq!(client.total_supply(), 15);
+}
+
+/// Test: mint deshabilitado y burn no puede crear deuda
+#[test]
+fn test_mutual_credit_disables_mint() {
+    let env = Env::default();
+    let client = setup_mutual_credit_token(&env);
+    let newcomer = Address::generate(&env);
+
+    assert!(client.is_mutual_credit());
+    assert_eq!(client.try_mint(&newcomer, &10), Err(Ok(CodebitError::MintDisabled)));
+
+    client.set_credit_limit(&newcomer, &60);
+    assert_eq!(client.try_burn(&newcomer, &10), Err(Ok(CodebitError::InsufficientBalance)));
+
+    // Al desactivar el modo vuelve el mint
+    client.set_mutual_credit(&false);
+    client.mint(&newcomer, &10);
+    assert_eq!(client.total_supply(), 10);
+
+    assert_eq!(client.try_set_credit_limit(&newcomer, &-1), Err(Ok(CodebitError::InvalidAmount)));
+}
+
+// ============================================================================
+// 1️⃣1️⃣ TESTS DE RECOMPENSAS PRO-RATA (SPONSORS)
+// ============================================================================
+
+/// Test: una donación en USDC se reparte según los balances de CODEBIT
+#[test]
+fn test_rewards_pro_rata_distribution() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 300);
+    client.mint(&bob, &100);
+
+    // USDC de prueba vía Stellar Asset Contract
+    let issuer = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(issuer).address();
+    let usdc_admin = soroban_sdk::token::StellarAssetClient::new(&env, &usdc);
+    let usdc_client = soroban_sdk::token::TokenClient::new(&env, &usdc);
+    usdc_admin.mint(&sponsor, &2_000);
+    client.add_reward_token(&usdc);
+
+    // 400 USDC entre 400 CODEBIT: 300 para Alice, 100 para Bob
+    client.fund_rewards(&sponsor, &usdc, &400);
+    assert_eq!(client.pending_rewards(&alice, &usdc), 300);
+    assert_eq!(client.pending_rewards(&bob, &usdc), 100);
+
+    // Alice transfiere: lo ganado antes se conserva
+    client.transfer(&alice, &bob, &200);
+    assert_eq!(client.pending_rewards(&alice, &usdc), 300);
+
+    // Segunda donación con balances 100 / 300
+    client.fund_rewards(&sponsor, &usdc, &800);
+    assert_eq!(client.pending_rewards(&alice, &usdc), 500);
+    assert_eq!(client.pending_rewards(&bob, &usdc), 700);
+
+    assert_eq!(client.claim_rewards(&alice, &usdc), 500);
+    assert_eq!(client.claim_rewards(&bob, &usdc), 700);
+    assert_eq!(client.claim_rewards(&bob, &usdc), 0);
+    assert_eq!(usdc_client.balance(&alice), 500);
+    assert_eq!(usdc_client.balance(&bob), 700);
+    assert_eq!(usdc_client.balance(&client.address), 0);
+    assert_eq!(client.reward_tokens().len(), 1);
+}
+
+/// Test: nuevos holders no cobran donaciones anteriores
+#[test]
+fn test_rewards_new_holder_and_validations() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 100);
+
+    let issuer = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(issuer).address();
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc).mint(&sponsor, &1_000);
+    client.add_reward_token(&usdc);
+
+    client.fund_rewards(&sponsor, &usdc, &100);
+    client.transfer(&alice, &carol, &50);
+    assert_eq!(client.pending_rewards(&carol, &usdc), 0);
+    assert_eq!(client.pending_rewards(&alice, &usdc), 100);
+
+    let result = client.try_fund_rewards(&sponsor, &usdc, &0);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidAmount)));
+    let result = client.try_add_reward_token(&client.address);
+    assert_eq!(result, Err(Ok(CodebitError::InvalidConfig)));
+
+    // Sin holders no hay entre quienes repartir
+    client.burn(&alice, &50);
+    client.burn(&carol, &50);
+    let result = client.try_fund_rewards(&sponsor, &usdc, &10);
+    assert_eq!(result, Err(Ok(CodebitError::NoEligibleHolders)));
+}
+
+/// Test: donar un token basura no ocupa lugares de MAX_REWARD_TOKENS
+#[test]
+fn test_rewards_unlisted_token_rejected() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 100);
+
+    // Cinco tokens propios del atacante con 1 unidad cada uno
+    for _ in 0..5 {
+        let junk = env.register_stellar_asset_contract_v2(attacker.clone()).address();
+        soroban_sdk::token::StellarAssetClient::new(&env, &junk).mint(&attacker, &1);
+        let result = client.try_fund_rewards(&attacker, &junk, &1);
+        assert_eq!(result, Err(Ok(CodebitError::RewardTokenNotListed)));
+    }
+    assert_eq!(client.reward_tokens().len(), 0);
+
+    // El admin sigue pudiendo habilitar el token real
+    let issuer = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(issuer).address();
+    client.add_reward_token(&usdc);
+    assert_eq!(client.reward_tokens(), vec![&env, usdc.clone()]);
+
+    // Con el timelock activo habilitar un token pasa por la cola
+    env.ledger().set_sequence_number(1_000);
+    client.enable_timelock(&100, &50);
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let result = client.try_add_reward_token(&other);
+    assert_eq!(result, Err(Ok(CodebitError::TimelockRequired)));
+}
+
+// ============================================================================
+// 1️⃣2️⃣ TESTS DE CONSEJO MULTISIG
+// ============================================================================
+
+/// Test: mint 2-of-3 vía propuesta, el admin único ya no alcanza
+#[test]
+fn test_council_mint_requires_threshold() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 100);
+    let (ana, beto, caro) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+
+    client.enable_council(&vec![&env, ana.clone(), beto.clone(), caro.clone()], &2);
+    assert_eq!(client.try_mint(&dev, &50), Err(Ok(CodebitError::CouncilRequired)));
+
+    let id = client.propose(&ana, &CouncilAction::Mint(dev.clone(), 50));
+    assert_eq!(client.try_execute_proposal(&id), Err(Ok(CodebitError::ThresholdNotMet)));
+    assert_eq!(client.try_approve_proposal(&ana, &id), Err(Ok(CodebitError::AlreadyApproved)));
+
+    client.approve_proposal(&caro, &id);
+    client.execute_proposal(&id);
+    assert_eq!(client.balance(&dev), 150);
+
+    // Una propuesta se ejecuta una sola vez
+    assert_eq!(client.try_execute_proposal(&id), Err(Ok(CodebitError::ProposalNotFound)));
+
+    // Quien no es firmante no propone
+    let result = client.try_propose(&dev, &CouncilAction::Mint(dev.clone(), 1));
+    assert_eq!(result, Err(Ok(CodebitError::Unauthorized)));
+}
+
+/// Test: la rotación de firmantes también requiere el threshold
+#[test]
+fn test_council_rotation() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 100);
+    let (ana, beto, caro) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let dani = Address::generate(&env);
+
+    client.enable_council(&vec![&env, ana.clone(), beto.clone(), caro.clone()], &2);
+    assert_eq!(
+        client.try_enable_council(&vec![&env, dani.clone()], &1),
+        Err(Ok(CodebitError::InvalidConfig))
+    );
+
+    // Propuesta pendiente antes de rotar
+    let stale = client.propose(&ana, &CouncilAction::Mint(dev.clone(), 10));
+
+    let rotation = client.propose(&beto, &CouncilAction::RotateSigners(vec![&env, caro.clone(), dani.clone()], 2));
+    assert_eq!(client.try_execute_proposal(&rotation), Err(Ok(CodebitError::ThresholdNotMet)));
+    client.approve_proposal(&caro, &rotation);
+    client.execute_proposal(&rotation);
+
+    let council = client.council().unwrap();
+    assert_eq!(council.signers, vec![&env, caro.clone(), dani.clone()]);
+    assert_eq!(council.epoch, 1);
+
+    // Ana ya no es firmante y la propuesta vieja quedó invalidada
+    assert_eq!(client.try_approve_proposal(&ana, &stale), Err(Ok(CodebitError::Unauthorized)));
+    assert_eq!(client.try_approve_proposal(&dani, &stale), Err(Ok(CodebitError::ProposalNotFound)));
+
+    // Threshold inválido se rechaza al ejecutar
+    let bad = client.propose(&caro, &CouncilAction::RotateSigners(vec![&env, caro.clone()], 2));
+    client.approve_proposal(&dani, &bad);
+    assert_eq!(client.try_execute_proposal(&bad), Err(Ok(CodebitError::InvalidConfig)));
+
+    // Volver al admin único
+    let disable = client.propose(&caro, &CouncilAction::DisableCouncil);
+    client.approve_proposal(&dani, &disable);
+    client.execute_proposal(&disable);
+    assert_eq!(client.council(), None);
+    client.mint(&dev, &5);
+    assert_eq!(client.balance(&dev), 105);
+}
+
+/// Test: con el timelock activo el consejo se instala, rota y desactiva
+/// solo a través de la cola
+#[test]
+fn test_council_governance_requires_timelock() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 100);
+    let (ana, beto) = (Address::generate(&env), Address::generate(&env));
+
+    env.ledger().set_sequence_number(1_000);
+    client.enable_timelock(&100, &50);
+
+    // El admin no puede instalarse un consejo 1-of-1 sin anunciarlo
+    let result = client.try_enable_council(&vec![&env, ana.clone()], &1);
+    assert_eq!(result, Err(Ok(CodebitError::TimelockRequired)));
+
+    let id = client.queue_admin_action(&TimelockAction::EnableCouncil(vec![&env, ana.clone()], 1), &1_100);
+    env.ledger().set_sequence_number(1_100);
+    client.execute_admin_action(&id);
+    assert_eq!(client.council().unwrap().signers, vec![&env, ana.clone()]);
+
+    // El consejo tampoco rota ni se desactiva directo
+    let rotation = client.propose(&ana, &CouncilAction::RotateSigners(vec![&env, beto.clone()], 1));
+    assert_eq!(client.try_execute_proposal(&rotation), Err(Ok(CodebitError::TimelockRequired)));
+    let disable = client.propose(&ana, &CouncilAction::DisableCouncil);
+    assert_eq!(client.try_execute_proposal(&disable), Err(Ok(CodebitError::TimelockRequired)));
+
+    // Por la cola sí, después del delay
+    let action = TimelockAction::RotateSigners(vec![&env, beto.clone()], 1);
+    let queue = client.propose(&ana, &CouncilAction::QueueTimelock(action, 1_200));
+    client.execute_proposal(&queue);
+    assert_eq!(client.council().unwrap().signers, vec![&env, ana.clone()]);
+
+    env.ledger().set_sequence_number(1_200);
+    client.execute_admin_action(&1);
+    let council = client.council().unwrap();
+    assert_eq!(council.signers, vec![&env, beto.clone()]);
+    assert_eq!(council.epoch, 1);
+}
+
+// ============================================================================
+// 1️⃣3️⃣ TESTS DE TIMELOCK
+// ============================================================================
+
+/// Test: un cambio de admin se anuncia y solo se aplica tras el delay
+#[test]
+fn test_timelock_queue_and_execute() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 100);
+    let new_admin = Address::generate(&env);
+
+    env.ledger().set_sequence_number(1_000);
+    client.enable_timelock(&100, &50);
+
+    // Ya no se puede cambiar directo
+    assert_eq!(client.try_set_admin(&new_admin), Err(Ok(CodebitError::TimelockRequired)));
+    assert_eq!(client.try_set_mutual_credit(&true), Err(Ok(CodebitError::TimelockRequired)));
+
+    // El eta debe estar al menos min_delay ledgers adelante
+    let action = TimelockAction::TransferAdmin(new_admin.clone());
+    let result = client.try_queue_admin_action(&action, &1_099);
+    assert_eq!(result, Err(Ok(CodebitError::TimelockDelayTooShort)));
+
+    let id = client.queue_admin_action(&action, &1_100);
+    let (contract, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(contract, client.address);
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "timelock"), Symbol::new(&env, "queued"), id).into_val(&env)
+    );
+
+    assert_eq!(client.try_execute_admin_action(&id), Err(Ok(CodebitError::TimelockNotReady)));
+
+    env.ledger().set_sequence_number(1_100);
+    client.execute_admin_action(&id);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "timelock"), Symbol::new(&env, "executed"), id).into_val(&env)
+    );
+    assert_eq!(client.admin(), new_admin);
+
+    // Una operación se ejecuta una sola vez
+    assert_eq!(client.try_execute_admin_action(&id), Err(Ok(CodebitError::OperationNotFound)));
+}
+
+/// Test: cancelación, ventana de gracia y cambio de min_delay
+#[test]
+fn test_timelock_cancel_expire_and_min_delay() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 100);
+
+    env.ledger().set_sequence_number(1_000);
+    client.enable_timelock(&100, &50);
+
+    // Cancelada antes de ejecutarse
+    let cancelled = client.queue_admin_action(&TimelockAction::SetMutualCredit(true), &1_100);
+    client.cancel_admin_action(&cancelled);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "timelock"), Symbol::new(&env, "cancelled"), cancelled).into_val(&env)
+    );
+
+    // Vencida después de la ventana de gracia
+    let expired = client.queue_admin_action(&TimelockAction::SetMinDelay(10), &1_100);
+    let min_delay = client.queue_admin_action(&TimelockAction::SetMinDelay(500), &1_200);
+
+    env.ledger().set_sequence_number(1_200);
+    assert_eq!(client.try_execute_admin_action(&cancelled), Err(Ok(CodebitError::OperationNotFound)));
+    assert_eq!(client.try_execute_admin_action(&expired), Err(Ok(CodebitError::TimelockExpired)));
+    assert!(!client.is_mutual_credit());
+
+    // min_delay solo cambia a través del timelock
+    client.execute_admin_action(&min_delay);
+    assert_eq!(client.timelock_config(), Some(TimelockConfig { min_delay: 500, grace_period: 50 }));
+    let result = client.try_queue_admin_action(&TimelockAction::SetMutualCredit(true), &1_600);
+    assert_eq!(result, Err(Ok(CodebitError::TimelockDelayTooShort)));
+}
+
+// ============================================================================
+// 1️⃣4️⃣ TESTS DE STAKING Y TIERS
+// ============================================================================
+
+fn staking_config() -> StakingConfig {
+    StakingConfig { reviewer: 100, mentor: 500, maintainer: 1_000, cooldown_ledgers: 50 }
+}
+
+/// Test: el tier sube con el stake y baja al pedir el unstake
+#[test]
+fn test_staking_tiers_and_cooldown() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 1_200);
+
+    assert_eq!(client.try_stake(&dev, &100), Err(Ok(CodebitError::ModeDisabled)));
+    client.set_staking_config(&staking_config());
+
+    client.stake(&dev, &400);
+    assert_eq!(client.tier_of(&dev), Tier::Reviewer);
+    client.stake(&dev, &600);
+    assert_eq!(client.tier_of(&dev), Tier::Maintainer);
+    assert_eq!(client.balance(&dev), 200);
+    assert_eq!(client.balance(&client.address), 1_000);
+    assert!(client.tier_of(&dev) >= Tier::Mentor);
+
+    // Lo que entra en cooldown deja de contar para el tier
+    env.ledger().set_sequence_number(100);
+    client.request_unstake(&dev, &700);
+    assert_eq!(client.tier_of(&dev), Tier::Reviewer);
+    assert_eq!(client.stake_of(&dev), Stake { amount: 300, unstaking: 700, unlock_ledger: 150 });
+    assert_eq!(client.try_request_unstake(&dev, &301), Err(Ok(CodebitError::InsufficientBalance)));
+
+    assert_eq!(client.try_withdraw(&dev), Err(Ok(CodebitError::CooldownActive)));
+    env.ledger().set_sequence_number(150);
+    assert_eq!(client.withdraw(&dev), 700);
+    assert_eq!(client.balance(&dev), 900);
+    assert_eq!(client.try_withdraw(&dev), Err(Ok(CodebitError::InvalidAmount)));
+
+    // Umbrales desordenados se rechazan
+    let bad = StakingConfig { mentor: 50, ..staking_config() };
+    assert_eq!(client.try_set_staking_config(&bad), Err(Ok(CodebitError::InvalidConfig)));
+}
+
+/// Test: el slashing quema primero el stake activo y luego el cooldown
+#[test]
+fn test_staking_slash() {
+    let env = Env::default();
+    let dev = Address::generate(&env);
+    let client = setup_stream_token(&env, &dev, 1_000);
+    client.set_staking_config(&staking_config());
+
+    client.stake(&dev, &600);
+    client.request_unstake(&dev, &400);
+    assert_eq!(client.tier_of(&dev), Tier::Reviewer);
+
+    client.slash(&dev, &300);
+    assert_eq!(client.stake_of(&dev), Stake { amount: 0, unstaking: 300, unlock_ledger: 50 });
+    assert_eq!(client.tier_of(&dev), Tier::None);
+    assert_eq!(client.total_supply(), 700);
+    assert_eq!(client.balance(&client.address), 300);
+
+    assert_eq!(client.try_slash(&dev, &301), Err(Ok(CodebitError::InvalidAmount)));
+}
+
+// ============================================================================
+// 1️⃣5️⃣ TESTS DE MODO ALLOWLIST
+// ============================================================================
+
+/// Test: con el modo activo solo los miembros reciben CODEBIT
+#[test]
+fn test_allowlist_recipients() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 100);
+    let (bob, outsider) = (Address::generate(&env), Address::generate(&env));
+
+    client.set_allowlist_mode(&true, &false);
+    client.add_members(&client.admin(), &vec![&env, bob.clone()]);
+
+    client.transfer(&alice, &bob, &10);
+    assert_eq!(client.try_transfer(&alice, &outsider, &10), Err(Ok(CodebitError::NotAllowlisted)));
+    assert_eq!(client.try_mint(&outsider, &10), Err(Ok(CodebitError::NotAllowlisted)));
+
+    client.approve(&alice, &bob, &20);
+    let result = client.try_transfer_from(&bob, &alice, &outsider, &20);
+    assert_eq!(result, Err(Ok(CodebitError::NotAllowlisted)));
+
+    // Al desactivar el modo vuelve a circular libremente
+    client.set_allowlist_mode(&false, &false);
+    client.transfer(&alice, &outsider, &10);
+    assert_eq!(client.balance(&outsider), 10);
+}
+
+/// Test: registrars gestionan miembros en lote y se puede restringir senders
+#[test]
+fn test_allowlist_registrars_and_senders() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 100);
+    let (bob, carol, registrar) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+
+    client.set_allowlist_mode(&true, &true);
+
+    // Quien no es admin ni registrar no gestiona miembros
+    let result = client.try_add_members(&registrar, &vec![&env, bob.clone()]);
+    assert_eq!(result, Err(Ok(CodebitError::Unauthorized)));
+
+    client.set_registrar(&registrar, &true);
+    client.add_members(&registrar, &vec![&env, bob.clone(), carol.clone()]);
+    assert!(client.is_allowlisted(&bob) && client.is_allowlisted(&carol));
+
+    // Alice no es miembro: no puede enviar aunque Bob sí lo sea
+    assert_eq!(client.try_transfer(&alice, &bob, &10), Err(Ok(CodebitError::NotAllowlisted)));
+
+    client.add_members(&registrar, &vec![&env, alice.clone()]);
+    client.transfer(&alice, &bob, &10);
+
+    client.remove_members(&registrar, &vec![&env, bob.clone()]);
+    assert!(!client.is_allowlisted(&bob));
+    assert_eq!(client.try_transfer(&bob, &carol, &5), Err(Ok(CodebitError::NotAllowlisted)));
+}
+
+/// Test: los streams no sirven para pagarle a quien no es miembro
+#[test]
+fn test_allowlist_streams() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let (bob, outsider) = (Address::generate(&env), Address::generate(&env));
+
+    client.set_allowlist_mode(&true, &false);
+    client.add_members(&client.admin(), &vec![&env, bob.clone()]);
+
+    let result = client.try_create_stream(&alice, &outsider, &1, &1_000, &1_100);
+    assert_eq!(result, Err(Ok(CodebitError::NotAllowlisted)));
+
+    // Bob sale de la allowlist a mitad de los streams
+    let first = client.create_stream(&alice, &bob, &1, &1_000, &1_100);
+    let second = client.create_stream(&alice, &bob, &1, &1_000, &1_100);
+    env.ledger().set_timestamp(1_040);
+    client.remove_members(&client.admin(), &vec![&env, bob.clone()]);
+    assert_eq!(client.try_withdraw_from_stream(&first), Err(Ok(CodebitError::NotAllowlisted)));
+
+    // Cancelar devuelve lo no liberado al payer y deja lo acumulado en custodia
+    client.cancel_stream(&alice, &second);
+    assert_eq!(client.balance(&alice), 860);
+    assert_eq!(client.balance(&bob), 0);
+    let stream = client.get_stream(&second);
+    assert_eq!((stream.stop, stream.deposit), (1_040, 40));
+
+    // Si vuelve a ser miembro retira lo que le corresponde
+    client.add_members(&client.admin(), &vec![&env, bob.clone()]);
+    env.ledger().set_timestamp(1_090);
+    assert_eq!(client.withdraw_from_stream(&second), 40);
+    assert_eq!(client.withdraw_from_stream(&first), 90);
+    assert_eq!(client.try_get_stream(&second), Err(Ok(CodebitError::StreamNotFound)));
+}
+
+// ============================================================================
+// 1️⃣6️⃣ TESTS DE LÍMITES DE VELOCIDAD
+// ============================================================================
+
+/// Test: el límite del admin cubre transfer, transfer_from y burn
+#[test]
+fn test_default_rate_limit() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let (bob, bot) = (Address::generate(&env), Address::generate(&env));
+
+    env.ledger().set_sequence_number(100);
+    client.set_default_rate_limit(&100, &50);
+
+    client.transfer(&alice, &bob, &60);
+    assert_eq!(client.try_transfer(&alice, &bob, &41), Err(Ok(CodebitError::RateLimitExceeded)));
+
+    client.approve(&alice, &bot, &500);
+    assert_eq!(client.try_transfer_from(&bot, &alice, &bob, &41), Err(Ok(CodebitError::RateLimitExceeded)));
+    assert_eq!(client.try_burn(&alice, &41), Err(Ok(CodebitError::RateLimitExceeded)));
+    client.burn(&alice, &40);
+
+    // Nueva ventana
+    env.ledger().set_sequence_number(150);
+    client.transfer_from(&bot, &alice, &bob, &100);
+    assert_eq!(client.balance(&bob), 160);
+
+    // Cada cuenta tiene su propia ventana
+    client.transfer(&bob, &alice, &100);
+}
+
+/// Test: la ventana es móvil, gastar al final de una ventana no libera
+/// otro máximo en el ledger siguiente
+#[test]
+fn test_rate_limit_rolling_window() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.set_default_rate_limit(&100, &50);
+    client.transfer(&alice, &bob, &10);
+
+    // Al borde de la "ventana" 100..149 se gasta el resto
+    env.ledger().set_sequence_number(149);
+    client.transfer(&alice, &bob, &90);
+
+    // El ledger siguiente solo libera lo enviado en el ledger 100
+    env.ledger().set_sequence_number(150);
+    assert_eq!(client.try_transfer(&alice, &bob, &11), Err(Ok(CodebitError::RateLimitExceeded)));
+    client.transfer(&alice, &bob, &10);
+
+    // Lo de 149 recién sale de la ventana 50 ledgers después
+    env.ledger().set_sequence_number(198);
+    assert_eq!(client.try_transfer(&alice, &bob, &1), Err(Ok(CodebitError::RateLimitExceeded)));
+    env.ledger().set_sequence_number(199);
+    client.transfer(&alice, &bob, &90);
+    assert_eq!(client.balance(&bob), 200);
+}
+
+/// Test: un stream no saltea el límite (el depósito cuenta como enviado)
+#[test]
+fn test_rate_limit_stream_deposit() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.set_default_rate_limit(&100, &50);
+    client.transfer(&alice, &bob, &60);
+
+    // 50 CODEBIT de depósito superan los 40 que quedan en la ventana
+    let result = client.try_create_stream(&alice, &bob, &1, &1_000, &1_050);
+    assert_eq!(result, Err(Ok(CodebitError::RateLimitExceeded)));
+    assert_eq!(client.balance(&alice), 940);
+
+    client.create_stream(&alice, &bob, &1, &1_000, &1_040);
+    assert_eq!(client.try_transfer(&alice, &bob, &1), Err(Ok(CodebitError::RateLimitExceeded)));
+}
+
+/// Test: el límite propio se ajusta ya, pero aflojarlo tarda
+#[test]
+fn test_self_rate_limit() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.set_self_rate_limit(&alice, &50, &100);
+    assert_eq!(client.try_transfer(&alice, &bob, &51), Err(Ok(CodebitError::RateLimitExceeded)));
+
+    // Quitar el límite no aplica hasta que pase el delay
+    client.set_self_rate_limit(&alice, &0, &0);
+    assert_eq!(client.try_transfer(&alice, &bob, &51), Err(Ok(CodebitError::RateLimitExceeded)));
+    let self_limit = client.self_rate_limit(&alice).unwrap();
+    assert_eq!(self_limit.pending_ledger, 100 + rate_limit::SELF_LIMIT_DELAY_LEDGERS);
+
+    // Ajustarlo sí aplica de inmediato y descarta el aflojamiento pendiente
+    client.set_self_rate_limit(&alice, &30, &100);
+    assert_eq!(client.self_rate_limit(&alice).unwrap().pending_ledger, 0);
+    assert_eq!(client.try_transfer(&alice, &bob, &31), Err(Ok(CodebitError::RateLimitExceeded)));
+
+    client.set_self_rate_limit(&alice, &500, &100);
+    env.ledger().set_sequence_number(100 + rate_limit::SELF_LIMIT_DELAY_LEDGERS);
+    client.transfer(&alice, &bob, &400);
+    assert_eq!(client.balance(&bob), 400);
+}
+
+// ============================================================================
+// 1️⃣7️⃣ TESTS DE ALLOWANCES ENUMERABLES
+// ============================================================================
+
+/// Test: el índice de spenders sigue a approve, transfer_from y revoke_all
+#[test]
+fn test_spenders_index_and_revoke_all() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let (bot_a, bot_b, bot_c, bob) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+
+    client.approve(&alice, &bot_a, &100);
+    client.approve(&alice, &bot_b, &50);
+    client.increase_allowance(&alice, &bot_c, &10);
+    assert_eq!(client.spenders_of(&alice, &0, &10), vec![&env, bot_a.clone(), bot_b.clone(), bot_c.clone()]);
+    assert_eq!(client.spenders_of(&alice, &1, &1), vec![&env, bot_b.clone()]);
+    assert_eq!(client.spenders_of(&alice, &5, &1).len(), 0);
+
+    // Allowance agotada o puesta en 0 sale del índice
+    client.transfer_from(&bot_b, &alice, &bob, &50);
+    client.approve(&alice, &bot_c, &0);
+    assert_eq!(client.spenders_of(&alice, &0, &10), vec![&env, bot_a.clone()]);
+
+    client.approve(&alice, &bot_c, &30);
+    assert_eq!(client.revoke_all(&alice), 2);
+    assert_eq!(client.allowance(&alice, &bot_a), 0);
+    assert_eq!(client.allowance(&alice, &bot_c), 0);
+    assert_eq!(client.spenders_of(&alice, &0, &10).len(), 0);
+}
+
+/// Test: allowances anteriores al índice se migran al tocarlas si hay
+/// lugar; con el owner en el tope siguen funcionando sin indexarse
+#[test]
+fn test_legacy_allowance_migrates_to_index() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let (legacy_bot, full_bot, forgotten_bot, bob) = (
+        Address::generate(&env), Address::generate(&env), Address::generate(&env), Address::generate(&env)
+    );
+
+    // Allowances escritas antes de que existiera DataKey::Spenders
+    env.as_contract(&client.address, || {
+        for bot in [&legacy_bot, &full_bot, &forgotten_bot] {
+            env.storage().persistent().set(&DataKey::Allowance(alice.clone(), bot.clone()), &100i128);
+        }
+    });
+
+    // Con lugar en el índice, gastar parte de la allowance vieja la indexa
+    client.transfer_from(&legacy_bot, &alice, &bob, &40);
+    assert_eq!(client.allowance(&alice, &legacy_bot), 60);
+    assert_eq!(client.spenders_of(&alice, &0, &1), vec![&env, legacy_bot.clone()]);
+
+    for _ in 1..50 {
+        client.approve(&alice, &Address::generate(&env), &1);
+    }
+    assert_eq!(client.try_approve(&alice, &bob, &1), Err(Ok(CodebitError::SpenderLimit)));
+
+    // Con el índice lleno la allowance vieja se sigue usando, sin pasar el tope
+    client.transfer_from(&full_bot, &alice, &bob, &40);
+    client.increase_allowance(&alice, &full_bot, &10);
+    assert_eq!(client.allowance(&alice, &full_bot), 70);
+    assert_eq!(client.spenders_of(&alice, &0, &100).len(), 50);
+
+    // revoke_all cubre solo lo indexado: las allowances sin indexar siguen vivas
+    assert_eq!(client.revoke_all(&alice), 50);
+    assert_eq!(client.allowance(&alice, &legacy_bot), 0);
+    assert_eq!(client.allowance(&alice, &full_bot), 70);
+    assert_eq!(client.allowance(&alice, &forgotten_bot), 100);
+    client.approve(&alice, &full_bot, &0);
+    client.approve(&alice, &forgotten_bot, &0);
+    assert_eq!(client.allowance(&alice, &forgotten_bot), 0);
+}
+
+/// Test: increase/decrease ajustan la allowance sin sobrescribirla
+#[test]
+fn test_increase_decrease_allowance() {
+    let env = Env::default();
+    let alice = Address::generate(&env);
+    let client = setup_stream_token(&env, &alice, 1_000);
+    let bot = Address::generate(&env);
+
+    client.increase_allowance(&alice, &bot, &40);
+    client.increase_allowance(&alice, &bot, &60);
+    assert_eq!(client.allowance(&alice, &bot), 100);
+
+    client.decrease_allowance(&alice, &bot, &30);
+    assert_eq!(client.allowance(&alice, &bot), 70);
+
+    let result = client.try_decrease_allowance(&alice, &bot, &71);
+    assert_eq!(result, Err(Ok(CodebitError::InsufficientAllowance)));
+
+    client.decrease_allowance(&alice, &bot, &70);
+    assert_eq!(client.spenders_of(&alice, &0, &10).len(), 0);
+    assert_eq!(client.try_increase_allowance(&alice, &bot, &0), Err(Ok(CodebitError::InvalidAmount)));
+}
@@ -0,0 +1,230 @@
+// src/timelock.rs
+use soroban_sdk::{contractevent, contractimpl, contracttype, Address, Env, Vec};
+
+use crate::council;
+use crate::errors::TokenError as CodebitError;
+use crate::staking::StakingConfig;
+use crate::storage::DataKey;
+use crate::{
+    require_admin, CodebitTrait, CouncilTrait, DemurrageTrait, MutualCreditTrait, RateLimitTrait, RewardsTrait,
+    StakingTrait, TokenCodebit, TokenCodebitArgs, TokenCodebitClient
+};
+
+/// Configuración del timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelockConfig {
+    /// Ledgers mínimos entre queue y ejecución
+    pub min_delay: u32,
+    /// Ledgers después del eta en que la operación sigue siendo ejecutable
+    pub grace_period: u32,
+}
+
+/// Cambios sensibles que deben anunciarse antes de aplicarse
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    TransferAdmin(Address),
+    SetDemurrageRate(u32),
+    SetDemurrageExempt(Address, bool),
+    SetMutualCredit(bool),
+    SetCreditLimit(Address, i128),
+    SetStakingConfig(StakingConfig),
+    /// (max_amount, window_ledgers)
+    SetDefaultRateLimit(i128, u32),
+    AddRewardToken(Address),
+    /// Cambios de gobierno: (firmantes, threshold)
+    EnableCouncil(Vec<Address>, u32),
+    RotateSigners(Vec<Address>, u32),
+    DisableCouncil,
+    /// min_delay solo cambia a través del propio timelock
+    SetMinDelay(u32),
+}
+
+/// Operación encolada esperando su ledger de ejecución
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedOperation {
+    pub action: TimelockAction,
+    /// Primer ledger en que se puede ejecutar
+    pub eta: u32,
+}
+
+#[contractevent(topics = ["timelock", "queued"])]
+pub struct OperationQueued {
+    #[topic]
+    pub id: u64,
+    pub action: TimelockAction,
+    pub eta: u32,
+}
+
+#[contractevent(topics = ["timelock", "executed"])]
+pub struct OperationExecuted {
+    #[topic]
+    pub id: u64,
+    pub action: TimelockAction,
+}
+
+#[contractevent(topics = ["timelock", "cancelled"])]
+pub struct OperationCancelled {
+    #[topic]
+    pub id: u64,
+}
+
+/// Trait del timelock para acciones administrativas sensibles
+///
+/// Con el timelock activo, los cambios de reglas de supply y del admin
+/// ya no se aplican directo: se encolan con un eta de al menos
+/// `min_delay` ledgers, emiten eventos y se ejecutan dentro de la
+/// ventana `[eta, eta + grace_period]`.
+pub trait TimelockTrait {
+    /// Activa el timelock (solo admin, no se puede desactivar)
+    fn enable_timelock(env: Env, min_delay: u32, grace_period: u32) -> Result<(), CodebitError>;
+
+    /// El admin encola una acción para el ledger `eta`
+    fn queue_admin_action(env: Env, action: TimelockAction, eta: u32) -> Result<u64, CodebitError>;
+
+    /// El admin cancela una acción encolada
+    fn cancel_admin_action(env: Env, operation_id: u64) -> Result<(), CodebitError>;
+
+    /// Cualquiera ejecuta la acción dentro de la ventana de gracia
+    fn execute_admin_action(env: Env, operation_id: u64) -> Result<(), CodebitError>;
+
+    // Métodos de consulta
+    fn timelock_config(env: Env) -> Option<TimelockConfig>;
+    fn get_queued_action(env: Env, operation_id: u64) -> Result<QueuedOperation, CodebitError>;
+}
+
+#[contractimpl]
+impl TimelockTrait for TokenCodebit {
+    fn enable_timelock(env: Env, min_delay: u32, grace_period: u32) -> Result<(), CodebitError> {
+        if is_enabled(&env) {
+            return Err(CodebitError::InvalidConfig);
+        }
+        require_admin(&env)?;
+
+        if grace_period == 0 {
+            return Err(CodebitError::InvalidConfig);
+        }
+
+        env.storage().instance().set(&DataKey::Timelock, &TimelockConfig { min_delay, grace_period });
+
+        Ok(())
+    }
+
+    fn queue_admin_action(env: Env, action: TimelockAction, eta: u32) -> Result<u64, CodebitError> {
+        let config = read_config(&env).ok_or(CodebitError::ModeDisabled)?;
+        require_admin(&env)?;
+
+        // El anuncio debe llegar al menos min_delay ledgers antes
+        if eta < env.ledger().sequence().saturating_add(config.min_delay) {
+            return Err(CodebitError::TimelockDelayTooShort);
+        }
+
+        let operation_id: u64 = env.storage().instance()
+            .get(&DataKey::TimelockCount)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::TimelockCount, &(operation_id + 1));
+
+        let key = DataKey::Queued(operation_id);
+        env.storage().persistent().set(&key, &QueuedOperation { action: action.clone(), eta });
+        env.storage().persistent().extend_ttl(&key, 100_000, 200_000);
+
+        OperationQueued { id: operation_id, action, eta }.publish(&env);
+
+        Ok(operation_id)
+    }
+
+    fn cancel_admin_action(env: Env, operation_id: u64) -> Result<(), CodebitError> {
+        require_admin(&env)?;
+        read_queued(&env, operation_id)?;
+
+        env.storage().persistent().remove(&DataKey::Queued(operation_id));
+        OperationCancelled { id: operation_id }.publish(&env);
+
+        Ok(())
+    }
+
+    fn execute_admin_action(env: Env, operation_id: u64) -> Result<(), CodebitError> {
+        let config = read_config(&env).ok_or(CodebitError::ModeDisabled)?;
+        let operation = read_queued(&env, operation_id)?;
+
+        // Solo dentro de [eta, eta + grace_period]
+        let now = env.ledger().sequence();
+        if now < operation.eta {
+            return Err(CodebitError::TimelockNotReady);
+        }
+        if now > operation.eta.saturating_add(config.grace_period) {
+            return Err(CodebitError::TimelockExpired);
+        }
+
+        env.storage().persistent().remove(&DataKey::Queued(operation_id));
+
+        // require_config_admin() acepta la operación solo mientras dura este flag
+        env.storage().temporary().set(&DataKey::TimelockExecuting, &true);
+        let result = match operation.action.clone() {
+            TimelockAction::TransferAdmin(new_admin) =>
+                TokenCodebit::set_admin(env.clone(), new_admin),
+            TimelockAction::SetDemurrageRate(rate_bps) =>
+                TokenCodebit::set_demurrage_rate(env.clone(), rate_bps),
+            TimelockAction::SetDemurrageExempt(account, exempt) =>
+                TokenCodebit::set_demurrage_exempt(env.clone(), account, exempt),
+            TimelockAction::SetMutualCredit(enabled) =>
+                TokenCodebit::set_mutual_credit(env.clone(), enabled),
+            TimelockAction::SetCreditLimit(account, limit) =>
+                TokenCodebit::set_credit_limit(env.clone(), account, limit),
+            TimelockAction::SetStakingConfig(config) =>
+                TokenCodebit::set_staking_config(env.clone(), config),
+            TimelockAction::SetDefaultRateLimit(max_amount, window_ledgers) =>
+                TokenCodebit::set_default_rate_limit(env.clone(), max_amount, window_ledgers),
+            TimelockAction::AddRewardToken(reward_token) =>
+                TokenCodebit::add_reward_token(env.clone(), reward_token),
+            TimelockAction::EnableCouncil(signers, threshold) =>
+                TokenCodebit::enable_council(env.clone(), signers, threshold),
+            TimelockAction::RotateSigners(signers, threshold) =>
+                council::rotate_signers(&env, signers, threshold),
+            TimelockAction::DisableCouncil =>
+                council::disable_council(&env),
+            TimelockAction::SetMinDelay(min_delay) => {
+                env.storage().instance().set(&DataKey::Timelock, &TimelockConfig {
+                    min_delay,
+                    grace_period: config.grace_period,
+                });
+                Ok(())
+            }
+        };
+        env.storage().temporary().remove(&DataKey::TimelockExecuting);
+        result?;
+
+        OperationExecuted { id: operation_id, action: operation.action }.publish(&env);
+
+        Ok(())
+    }
+
+    fn timelock_config(env: Env) -> Option<TimelockConfig> {
+        read_config(&env)
+    }
+
+    fn get_queued_action(env: Env, operation_id: u64) -> Result<QueuedOperation, CodebitError> {
+        read_queued(&env, operation_id)
+    }
+}
+
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Timelock)
+}
+
+/// true solo dentro de execute_admin_action()
+pub fn is_executing(env: &Env) -> bool {
+    env.storage().temporary().has(&DataKey::TimelockExecuting)
+}
+
+fn read_config(env: &Env) -> Option<TimelockConfig> {
+    env.storage().instance().get(&DataKey::Timelock)
+}
+
+fn read_queued(env: &Env, operation_id: u64) -> Result<QueuedOperation, CodebitError> {
+    env.storage().persistent()
+        .get(&DataKey::Queued(operation_id))
+        .ok_or(CodebitError::OperationNotFound)
+}